@@ -0,0 +1,81 @@
+//! Generate names that are unique within a scope.
+//!
+//! # Examples
+//!
+//! ```
+//! use naming_lib::allocator::NameAllocator;
+//! use naming_lib::TargetCase;
+//!
+//! let mut names = NameAllocator::new(TargetCase::Snake);
+//! names.reserve("type");
+//!
+//! assert_eq!("foo_bar", names.allocate("fooBar").unwrap());
+//! assert_eq!("foo_bar_2", names.allocate("FooBar").unwrap());
+//! assert_eq!("foo_bar_3", names.allocate("foo bar").unwrap());
+//! assert_eq!("type_2", names.allocate("Type").unwrap());
+//! ```
+
+use std::collections::HashSet;
+
+use crate::batch::append_suffix;
+use crate::{detector, TargetCase};
+
+/// Convert raw strings to names in the target case,
+/// and keep every issued name unique by appending numbers from 2
+/// formatted in the target case, like `foo_bar_2`, `fooBar2` or `FOO_BAR_2`.
+///
+/// Raw strings are converted with
+/// [to_case_lossy()](crate::NamingCase::to_case_lossy()),
+/// so they don't have to be valid identifiers.
+#[derive(Clone, Debug)]
+pub struct NameAllocator {
+    target: TargetCase,
+    reserved: HashSet<String>,
+    issued: HashSet<String>,
+}
+
+impl NameAllocator {
+    /// Create an allocator without any reserved name.
+    pub fn new(target: TargetCase) -> NameAllocator {
+        NameAllocator {
+            target,
+            reserved: HashSet::new(),
+            issued: HashSet::new(),
+        }
+    }
+
+    /// Never issue this name, compared after conversion.
+    pub fn reserve(&mut self, name: &str) {
+        self.reserved.insert(name.to_string());
+    }
+
+    /// Check if a name is reserved or already issued.
+    pub fn is_taken(&self, name: &str) -> bool {
+        self.reserved.contains(name) || self.issued.contains(name)
+    }
+
+    /// Get all names issued so far, in arbitrary order.
+    pub fn issued(&self) -> impl Iterator<Item = &str> {
+        self.issued.iter().map(|name| name.as_str())
+    }
+
+    /// Convert a raw string to a name that hasn't been issued or reserved.
+    ///
+    /// # Errors
+    ///
+    /// Get an [Err](core::result::Result::Err)
+    /// if there isn't any word in the raw string.
+    pub fn allocate(&mut self, raw: &str) -> Result<String, &'static str> {
+        let name = detector::which_case(raw).to_case_lossy(self.target)?;
+
+        let mut candidate = name.clone();
+        let mut n = 2;
+        while self.is_taken(&candidate) {
+            candidate = append_suffix(&name, &n.to_string(), self.target);
+            n += 1;
+        }
+
+        self.issued.insert(candidate.clone());
+        Ok(candidate)
+    }
+}
@@ -0,0 +1,197 @@
+//! Convert a batch of identifiers to one case,
+//! and find the ones that clash after conversion.
+//!
+//! # Examples
+//!
+//! ```
+//! use naming_lib::batch::{convert_batch, Disambiguate};
+//! use naming_lib::TargetCase;
+//!
+//! let ids = ["fooBar", "foo_bar", "userId"];
+//!
+//! let result = convert_batch(ids, TargetCase::Snake, &Disambiguate::No);
+//! assert_eq!(1, result.collisions.len());
+//! assert_eq!("foo_bar", result.collisions[0].converted);
+//! assert_eq!(vec!["fooBar", "foo_bar"], result.collisions[0].identifiers);
+//!
+//! let result = convert_batch(ids, TargetCase::Snake, &Disambiguate::Numeric);
+//! assert_eq!(vec!["foo_bar", "foo_bar_2", "user_id"], result.names());
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{detector, TargetCase};
+
+/// How to rename identifiers that clash after conversion.
+///
+/// The first identifier of a clashing group always keeps its converted name,
+/// the others get a suffix formatted in the target case,
+/// like `foo_bar_2`, `fooBar2` or `FOO_BAR_2`.
+/// A suffixed name that is already used is skipped.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Disambiguate {
+    /// Keep clashing names, only report them.
+    No,
+    /// Append numbers from 2.
+    Numeric,
+    /// Append the given words in order,
+    /// and numbers from 2 after all words are used.
+    Suffixes(Vec<String>),
+}
+
+impl Disambiguate {
+    fn nth_suffix(&self, n: usize) -> String {
+        match self {
+            Disambiguate::Suffixes(words) if n < words.len() => {
+                words[n].clone()
+            }
+            Disambiguate::Suffixes(words) => (n - words.len() + 2).to_string(),
+            _ => (n + 2).to_string(),
+        }
+    }
+}
+
+/// Identifiers that are converted to the same name.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Collision {
+    /// The name they are converted to.
+    pub converted: String,
+    /// The different identifiers, in the order they are given.
+    pub identifiers: Vec<String>,
+    /// Positions of the identifiers in the batch,
+    /// including repeated ones.
+    pub indices: Vec<usize>,
+}
+
+/// Result of [convert_batch()].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BatchConversion {
+    /// Converted name of each identifier, in the order they are given.
+    ///
+    /// An [Err](core::result::Result::Err) for an identifier
+    /// recognized as [Invalid](crate::CaseKind::Invalid).
+    pub converted: Vec<Result<String, &'static str>>,
+    /// Every group of clashing identifiers, found before disambiguation.
+    pub collisions: Vec<Collision>,
+}
+
+impl BatchConversion {
+    /// Get converted names of valid identifiers.
+    pub fn names(&self) -> Vec<&str> {
+        self.converted
+            .iter()
+            .filter_map(|name| name.as_ref().ok())
+            .map(|name| name.as_str())
+            .collect()
+    }
+}
+
+/// Convert every identifier to the target case,
+/// report and optionally rename the ones that clash.
+///
+/// Repeats of one identifier are not a collision,
+/// they always get the same name.
+pub fn convert_batch<I, S>(
+    identifiers: I,
+    target: TargetCase,
+    disambiguate: &Disambiguate,
+) -> BatchConversion
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let identifiers: Vec<String> =
+        identifiers.into_iter().map(|s| s.as_ref().to_string()).collect();
+    let mut converted: Vec<Result<String, &'static str>> = identifiers
+        .iter()
+        .map(|identifier| detector::which_case(identifier).to_case(target))
+        .collect();
+
+    // Group positions by converted names, in the order they first appear.
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut group_of_name: HashMap<&str, usize> = HashMap::new();
+    for (index, name) in converted.iter().enumerate() {
+        if let Ok(name) = name {
+            let group = *group_of_name.entry(name).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(index);
+        }
+    }
+
+    let collisions: Vec<Collision> = groups
+        .into_iter()
+        .filter_map(|indices| {
+            let mut distinct: Vec<String> = Vec::new();
+            for &index in &indices {
+                if !distinct.contains(&identifiers[index]) {
+                    distinct.push(identifiers[index].clone());
+                }
+            }
+            if distinct.len() < 2 {
+                return None;
+            }
+            Some(Collision {
+                converted: converted[indices[0]].clone().unwrap(),
+                identifiers: distinct,
+                indices,
+            })
+        })
+        .collect();
+
+    if *disambiguate != Disambiguate::No {
+        let mut taken: HashSet<String> =
+            converted.iter().filter_map(|name| name.clone().ok()).collect();
+
+        for collision in &collisions {
+            for identifier in &collision.identifiers[1..] {
+                let mut n = 0;
+                let renamed = loop {
+                    let candidate = append_suffix(
+                        &collision.converted,
+                        &disambiguate.nth_suffix(n),
+                        target,
+                    );
+                    if taken.insert(candidate.clone()) {
+                        break candidate;
+                    }
+                    n += 1;
+                };
+
+                for &index in &collision.indices {
+                    if identifiers[index] == *identifier {
+                        converted[index] = Ok(renamed.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    BatchConversion { converted, collisions }
+}
+
+/// Append a suffix word to a name in the target case,
+/// the suffix may be made of digits only, like `foo_bar_2` or `fooBar2`.
+pub(crate) fn append_suffix(
+    name: &str,
+    suffix: &str,
+    target: TargetCase,
+) -> String {
+    // Digits are not a valid identifier, keep them as they are.
+    let word = |case: TargetCase| {
+        detector::which_case(suffix)
+            .to_case(case)
+            .unwrap_or_else(|_| suffix.to_string())
+    };
+
+    match target {
+        TargetCase::ScreamingSnake | TargetCase::Snake => {
+            format!("{}_{}", name, word(target))
+        }
+        TargetCase::Kebab => format!("{}-{}", name, word(target)),
+        TargetCase::Camel | TargetCase::Pascal => {
+            format!("{}{}", name, word(TargetCase::Pascal))
+        }
+    }
+}
@@ -0,0 +1,389 @@
+//! Command-line interface of naming_lib.
+//!
+//! ```text
+//! naming detect [--json] [IDENTIFIER...]
+//! naming convert --to <CASE> [--json] [IDENTIFIER...]
+//! naming words [--json] [IDENTIFIER...]
+//! naming lint [--format <FORMAT>] [--json] [PATH...]
+//! naming lint --fix|--diff [--scope <SCOPE>] [PATH...]
+//! ```
+//!
+//! Identifiers are read from stdin line by line if none is given.
+//! Exit with 1 if any identifier is invalid or any violation is found,
+//! 2 on usage errors, or 3 if reading or writing fails
+//! (missing paths, bad config files and so on).
+
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::process;
+
+use naming_lib::lint::{self, Fixer, Format, Linter, Scope};
+use naming_lib::{which_case, NamingCase, TargetCase};
+
+use json::json_string;
+
+#[path = "../json.rs"]
+mod json;
+
+const USAGE: &str = "\
+Usage:
+    naming detect [--json] [IDENTIFIER...]
+    naming convert --to <CASE> [--json] [IDENTIFIER...]
+    naming words [--json] [IDENTIFIER...]
+    naming lint [--format <FORMAT>] [--json] [PATH...]
+    naming lint --fix|--diff [--scope <SCOPE>] [PATH...]
+
+Identifiers are read from stdin line by line if none is given.
+
+Commands:
+    detect     Print the case of each identifier
+    convert    Convert each identifier to the target case
+    words      Print the words of each identifier, separated by spaces
+    lint       Check naming conventions of source files in the paths,
+               or in the current directory if none is given,
+               with rules of .naming.toml files if built with `toml`
+
+Options:
+    --to <CASE>    screaming-snake, snake, kebab, camel or pascal
+    --format <FORMAT>
+                   Report violations in text, json, sarif, checkstyle
+                   or github format
+    --json         Print a JSON object per identifier,
+                   same as `--format json` for lint
+    --fix          Rename violations and their references in files,
+                   and report violations that can't be renamed
+    --diff         Print a unified diff of renames instead of writing them
+    --scope <SCOPE>
+                   Rename references in the declaring file or in every
+                   linted file, file (default) or project
+    -h, --help     Print this message
+
+Exit with 1 if any identifier is invalid or any violation is found,
+2 on usage errors, or 3 if reading or writing fails
+(missing paths, bad config files and so on).";
+
+const EXIT_INVALID: i32 = 1;
+const EXIT_USAGE: i32 = 2;
+const EXIT_ERROR: i32 = 3;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Command {
+    Detect,
+    Convert(TargetCase),
+    Words,
+    Lint,
+}
+
+#[derive(Debug)]
+struct Options {
+    command: Command,
+    json: bool,
+    /// Format of lint reports.
+    format: Option<Format>,
+    /// Write renames to files.
+    fix: bool,
+    /// Print renames as a diff.
+    diff: bool,
+    scope: Option<Scope>,
+    /// Identifiers, or paths to lint.
+    operands: Vec<String>,
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return;
+    }
+
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("naming: {}\n\n{}", message, USAGE);
+            process::exit(EXIT_USAGE);
+        }
+    };
+
+    match run(&options) {
+        Ok(true) => {}
+        Ok(false) => process::exit(EXIT_INVALID),
+        Err(e) => {
+            eprintln!("naming: {}", e);
+            process::exit(EXIT_ERROR);
+        }
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let (command, rest) = match args.split_first() {
+        Some((command, rest)) => (command.as_str(), rest),
+        None => return Err("missing command".to_string()),
+    };
+
+    let mut target = None;
+    let mut json = false;
+    let mut format = None;
+    let mut fix = false;
+    let mut diff = false;
+    let mut scope = None;
+    let mut operands = Vec::new();
+    let mut iter = rest.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--to" => {
+                let value = iter.next().ok_or("missing value of --to")?;
+                target = Some(parse_target(value)?);
+            }
+            "--format" => {
+                let value = iter.next().ok_or("missing value of --format")?;
+                format = Some(parse_format(value)?);
+            }
+            "--fix" => fix = true,
+            "--diff" => diff = true,
+            "--scope" => {
+                let value = iter.next().ok_or("missing value of --scope")?;
+                scope = Some(parse_scope(value)?);
+            }
+            "--" => operands.extend(iter.by_ref().cloned()),
+            _ if arg.starts_with("--to=") => {
+                target = Some(parse_target(&arg["--to=".len()..])?);
+            }
+            _ if arg.starts_with("--format=") => {
+                format = Some(parse_format(&arg["--format=".len()..])?);
+            }
+            _ if arg.starts_with("--scope=") => {
+                scope = Some(parse_scope(&arg["--scope=".len()..])?);
+            }
+            _ if arg.starts_with("--") => {
+                return Err(format!("unknown option `{}`", arg));
+            }
+            _ => operands.push(arg.clone()),
+        }
+    }
+
+    let command = match (command, target) {
+        ("detect", None) => Command::Detect,
+        ("words", None) => Command::Words,
+        ("lint", None) => Command::Lint,
+        ("convert", Some(target)) => Command::Convert(target),
+        ("convert", None) => return Err("missing option --to".to_string()),
+        ("detect" | "words" | "lint", Some(_)) => {
+            return Err(format!("`{}` doesn't take --to", command))
+        }
+        _ => return Err(format!("unknown command `{}`", command)),
+    };
+    let lint_only = [
+        ("--format", format.is_some()),
+        ("--fix", fix),
+        ("--diff", diff),
+        ("--scope", scope.is_some()),
+    ];
+    if let Some((option, _)) = lint_only.iter().find(|(_, given)| *given) {
+        if command != Command::Lint {
+            return Err(format!("`{}` doesn't take {}", args[0], option));
+        }
+    }
+    if fix && diff {
+        return Err("--fix and --diff can't be used together".to_string());
+    }
+    if diff && (format.is_some() || json) {
+        return Err("--diff doesn't take --format or --json".to_string());
+    }
+    if scope.is_some() && !fix && !diff {
+        return Err("--scope is only used with --fix or --diff".to_string());
+    }
+    Ok(Options { command, json, format, fix, diff, scope, operands })
+}
+
+fn parse_target(value: &str) -> Result<TargetCase, String> {
+    value.parse().map_err(|_| format!("unknown case `{}`", value))
+}
+
+fn parse_format(value: &str) -> Result<Format, String> {
+    value.parse().map_err(|_| format!("unknown format `{}`", value))
+}
+
+fn parse_scope(value: &str) -> Result<Scope, String> {
+    value.parse().map_err(|_| format!("unknown scope `{}`", value))
+}
+
+/// Process every identifier, return false if any of them is invalid,
+/// or lint the paths.
+fn run(options: &Options) -> io::Result<bool> {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    if options.command == Command::Lint {
+        return lint(options, &mut out);
+    }
+    let mut all_valid = true;
+
+    if options.operands.is_empty() {
+        for line in io::stdin().lock().lines() {
+            let line = line?;
+            let identifier = line.trim();
+            if !identifier.is_empty() {
+                all_valid &= process_one(options, identifier, &mut out)?;
+            }
+        }
+    } else {
+        for identifier in &options.operands {
+            all_valid &= process_one(options, identifier, &mut out)?;
+        }
+    }
+    Ok(all_valid)
+}
+
+fn process_one(
+    options: &Options,
+    identifier: &str,
+    out: &mut impl Write,
+) -> io::Result<bool> {
+    let case = which_case(identifier);
+    let result = match options.command {
+        Command::Detect => Ok(Output::Detected),
+        Command::Convert(target) => case.to_case(target).map(Output::Converted),
+        Command::Words => case.words().map(Output::Words),
+        Command::Lint => unreachable!("paths are linted in lint()"),
+    };
+
+    match (options.json, result) {
+        (true, result) => writeln!(out, "{}", to_json(&case, &result))?,
+        (false, Ok(Output::Detected)) => writeln!(out, "{}", case.kind())?,
+        (false, Ok(Output::Converted(converted))) => {
+            writeln!(out, "{}", converted)?
+        }
+        (false, Ok(Output::Words(words))) => {
+            writeln!(out, "{}", words.join(" "))?
+        }
+        (false, Err(e)) => eprintln!("naming: `{}`: {}", identifier, e),
+    }
+    Ok(!case.is_invalid())
+}
+
+/// Lint every path, return false if any violation is found.
+fn lint(options: &Options, out: &mut impl Write) -> io::Result<bool> {
+    let paths = if options.operands.is_empty() {
+        vec![".".to_string()]
+    } else {
+        options.operands.clone()
+    };
+
+    let format = match (options.format, options.json) {
+        (Some(format), _) => format,
+        (None, true) => Format::Json,
+        (None, false) => Format::Text,
+    };
+
+    if options.fix || options.diff {
+        return fix(options, &paths, format, out);
+    }
+
+    // Reports like SARIF are one document for all paths.
+    let mut diagnostics = Vec::new();
+    for path in paths {
+        let mut found = linter_for(Path::new(&path))
+            .and_then(|linter| linter.lint_path(Path::new(&path)))
+            .map_err(|e| {
+                io::Error::new(e.kind(), format!("`{}`: {}", path, e))
+            })?;
+        diagnostics.append(&mut found);
+    }
+    lint::report(&diagnostics, format, out)?;
+    Ok(diagnostics.is_empty())
+}
+
+/// Rename violations in every path and write them or print a diff,
+/// return false if any violation is found, or left with --fix.
+fn fix(
+    options: &Options,
+    paths: &[String],
+    format: Format,
+    out: &mut impl Write,
+) -> io::Result<bool> {
+    let mut fixer = Fixer::new(options.scope.unwrap_or_default());
+    for path in paths {
+        linter_for(Path::new(path))
+            .and_then(|linter| fixer.add_path(&linter, Path::new(path)))
+            .map_err(|e| {
+                io::Error::new(e.kind(), format!("`{}`: {}", path, e))
+            })?;
+    }
+    let fixes = fixer.fix();
+
+    for refusal in &fixes.refused {
+        eprintln!("naming: {}", refusal);
+    }
+    if options.diff {
+        for file in &fixes.files {
+            write!(out, "{}", file.diff())?;
+        }
+        return Ok(fixes.fixed.is_empty() && fixes.refused.is_empty());
+    }
+
+    for file in &fixes.files {
+        file.write().map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("`{}`: {}", file.path.display(), e),
+            )
+        })?;
+    }
+    let left: Vec<lint::Diagnostic> =
+        fixes.refused.into_iter().map(|refusal| refusal.diagnostic).collect();
+    lint::report(&left, format, out)?;
+    Ok(left.is_empty())
+}
+
+/// Get a linter with config files of directories containing the path.
+#[cfg(feature = "toml")]
+fn linter_for(path: &Path) -> io::Result<Linter> {
+    Linter::discover(path)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Get the default linter, warn about config files that can't be read
+/// in directories containing the path.
+#[cfg(not(feature = "toml"))]
+fn linter_for(path: &Path) -> io::Result<Linter> {
+    let absolute = std::fs::canonicalize(path)?;
+    if let Some(file) = absolute
+        .ancestors()
+        .map(|directory| directory.join(".naming.toml"))
+        .find(|file| file.is_file())
+    {
+        eprintln!(
+            "naming: warning: `{}` is skipped, \
+             config files are read only if built with `toml`",
+            file.display()
+        );
+    }
+    Ok(Linter::default())
+}
+
+enum Output {
+    Detected,
+    Converted(String),
+    Words(Vec<String>),
+}
+
+fn to_json(case: &NamingCase, result: &Result<Output, &str>) -> String {
+    let mut json = format!(
+        "{{\"identifier\":{},\"case\":{}",
+        json_string(case.as_str()),
+        json_string(case.kind().name())
+    );
+    match result {
+        Ok(Output::Detected) => {}
+        Ok(Output::Converted(converted)) => {
+            json += &format!(",\"converted\":{}", json_string(converted));
+        }
+        Ok(Output::Words(words)) => {
+            let words: Vec<String> =
+                words.iter().map(|word| json_string(word)).collect();
+            json += &format!(",\"words\":[{}]", words.join(","));
+        }
+        Err(e) => json += &format!(",\"error\":{}", json_string(e)),
+    }
+    json + "}"
+}
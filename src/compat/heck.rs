@@ -0,0 +1,257 @@
+//! Case conversion traits of [heck](https://docs.rs/heck/0.5),
+//! with the same names and the same outputs.
+//!
+//! heck splits a string on every non-alphanumeric character,
+//! then splits each part on case changes:
+//!
+//! - a lowercase letter followed by an uppercase letter ends a word
+//!   (`"fooBar"` -> `foo`, `Bar`),
+//! - an uppercase letter following uppercase letters and followed by
+//!   a lowercase letter starts a word (`"XMLHttp"` -> `XML`, `Http`),
+//! - digits don't change the case state, so they stick to the word before
+//!   (`"abc123Def"` -> `abc123`, `Def`; `"99BOTTLES"` -> `99BOTTLES`).
+//!
+//! # Examples
+//!
+//! ```
+//! use naming_lib::compat::heck::{ToLowerCamelCase, ToSnakeCase};
+//!
+//! assert_eq!("xml_http_request", "XMLHttpRequest".to_snake_case());
+//! assert_eq!("abc123_def456", "abc123DEF456".to_snake_case());
+//! assert_eq!("thisIsHumanCase", "This is Human case.".to_lower_camel_case());
+//! ```
+
+/// The case state of the last cased character in current word.
+#[derive(Clone, Copy, PartialEq)]
+enum WordMode {
+    Boundary,
+    Lowercase,
+    Uppercase,
+}
+
+fn split_words(s: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+
+    for part in s.split(|c: char| !c.is_alphanumeric()) {
+        let mut char_indices = part.char_indices().peekable();
+        let mut init = 0;
+        let mut mode = WordMode::Boundary;
+
+        while let Some((i, c)) = char_indices.next() {
+            if let Some(&(next_i, next)) = char_indices.peek() {
+                let next_mode = if c.is_lowercase() {
+                    WordMode::Lowercase
+                } else if c.is_uppercase() {
+                    WordMode::Uppercase
+                } else {
+                    mode
+                };
+
+                if next_mode == WordMode::Lowercase && next.is_uppercase() {
+                    words.push(&part[init..next_i]);
+                    init = next_i;
+                    mode = WordMode::Boundary;
+                } else if mode == WordMode::Uppercase
+                    && c.is_uppercase()
+                    && next.is_lowercase()
+                {
+                    words.push(&part[init..i]);
+                    init = i;
+                    mode = WordMode::Boundary;
+                } else {
+                    mode = next_mode;
+                }
+            } else {
+                words.push(&part[init..]);
+                break;
+            }
+        }
+    }
+
+    words
+}
+
+fn lowercase(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        // Final form of the greek sigma.
+        if c == 'Σ' && chars.peek().is_none() {
+            result.push('ς');
+        } else {
+            result.extend(c.to_lowercase());
+        }
+    }
+    result
+}
+
+fn uppercase(s: &str) -> String {
+    s.chars().flat_map(char::to_uppercase).collect()
+}
+
+fn capitalize(s: &str) -> String {
+    let mut char_indices = s.char_indices();
+    match char_indices.next() {
+        Some((_, first)) => {
+            let mut result: String = first.to_uppercase().collect();
+            if let Some((i, _)) = char_indices.next() {
+                result += &lowercase(&s[i..]);
+            }
+            result
+        }
+        None => String::new(),
+    }
+}
+
+fn transform(s: &str, with_word: fn(&str) -> String, boundary: &str) -> String {
+    split_words(s)
+        .into_iter()
+        .map(with_word)
+        .collect::<Vec<String>>()
+        .join(boundary)
+}
+
+/// Same as `heck::ToSnakeCase`.
+pub trait ToSnakeCase: ToOwned {
+    /// Convert this type to snake case.
+    fn to_snake_case(&self) -> Self::Owned;
+}
+
+impl ToSnakeCase for str {
+    fn to_snake_case(&self) -> String {
+        transform(self, lowercase, "_")
+    }
+}
+
+/// Same as `heck::ToSnekCase`, an alias of [ToSnakeCase].
+pub trait ToSnekCase: ToOwned {
+    /// Convert this type to snek case.
+    fn to_snek_case(&self) -> Self::Owned;
+}
+
+impl<T: ?Sized + ToSnakeCase> ToSnekCase for T {
+    fn to_snek_case(&self) -> Self::Owned {
+        self.to_snake_case()
+    }
+}
+
+/// Same as `heck::ToKebabCase`.
+pub trait ToKebabCase: ToOwned {
+    /// Convert this type to kebab case.
+    fn to_kebab_case(&self) -> Self::Owned;
+}
+
+impl ToKebabCase for str {
+    fn to_kebab_case(&self) -> String {
+        transform(self, lowercase, "-")
+    }
+}
+
+/// Same as `heck::ToLowerCamelCase`.
+pub trait ToLowerCamelCase: ToOwned {
+    /// Convert this type to lower camel case.
+    fn to_lower_camel_case(&self) -> Self::Owned;
+}
+
+impl ToLowerCamelCase for str {
+    fn to_lower_camel_case(&self) -> String {
+        split_words(self)
+            .into_iter()
+            .enumerate()
+            .map(
+                |(i, word)| {
+                    if i == 0 {
+                        lowercase(word)
+                    } else {
+                        capitalize(word)
+                    }
+                },
+            )
+            .collect()
+    }
+}
+
+/// Same as `heck::ToUpperCamelCase`.
+pub trait ToUpperCamelCase: ToOwned {
+    /// Convert this type to upper camel case.
+    fn to_upper_camel_case(&self) -> Self::Owned;
+}
+
+impl ToUpperCamelCase for str {
+    fn to_upper_camel_case(&self) -> String {
+        transform(self, capitalize, "")
+    }
+}
+
+/// Same as `heck::ToPascalCase`, an alias of [ToUpperCamelCase].
+pub trait ToPascalCase: ToOwned {
+    /// Convert this type to upper camel case.
+    fn to_pascal_case(&self) -> Self::Owned;
+}
+
+impl<T: ?Sized + ToUpperCamelCase> ToPascalCase for T {
+    fn to_pascal_case(&self) -> Self::Owned {
+        self.to_upper_camel_case()
+    }
+}
+
+/// Same as `heck::ToShoutySnakeCase`.
+pub trait ToShoutySnakeCase: ToOwned {
+    /// Convert this type to shouty snake case.
+    fn to_shouty_snake_case(&self) -> Self::Owned;
+}
+
+impl ToShoutySnakeCase for str {
+    fn to_shouty_snake_case(&self) -> String {
+        transform(self, uppercase, "_")
+    }
+}
+
+/// Same as `heck::ToShoutySnekCase`, an alias of [ToShoutySnakeCase].
+#[allow(non_snake_case)]
+pub trait ToShoutySnekCase: ToOwned {
+    /// CONVERT THIS TYPE TO SNEK CASE.
+    fn TO_SHOUTY_SNEK_CASE(&self) -> Self::Owned;
+}
+
+impl<T: ?Sized + ToShoutySnakeCase> ToShoutySnekCase for T {
+    fn TO_SHOUTY_SNEK_CASE(&self) -> Self::Owned {
+        self.to_shouty_snake_case()
+    }
+}
+
+/// Same as `heck::ToShoutyKebabCase`.
+pub trait ToShoutyKebabCase: ToOwned {
+    /// Convert this type to shouty kebab case.
+    fn to_shouty_kebab_case(&self) -> Self::Owned;
+}
+
+impl ToShoutyKebabCase for str {
+    fn to_shouty_kebab_case(&self) -> String {
+        transform(self, uppercase, "-")
+    }
+}
+
+/// Same as `heck::ToTitleCase`.
+pub trait ToTitleCase: ToOwned {
+    /// Convert this type to title case.
+    fn to_title_case(&self) -> Self::Owned;
+}
+
+impl ToTitleCase for str {
+    fn to_title_case(&self) -> String {
+        transform(self, capitalize, " ")
+    }
+}
+
+/// Same as `heck::ToTrainCase`.
+pub trait ToTrainCase: ToOwned {
+    /// Convert this type to train case.
+    fn to_train_case(&self) -> Self::Owned;
+}
+
+impl ToTrainCase for str {
+    fn to_train_case(&self) -> String {
+        transform(self, capitalize, "-")
+    }
+}
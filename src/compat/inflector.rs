@@ -0,0 +1,252 @@
+//! Case conversions of the [Inflector](https://docs.rs/Inflector/0.11) trait,
+//! with the same method names and the same outputs.
+//!
+//! Only conversions between cases are provided,
+//! Inflector's pluralization, ordinal and foreign key helpers are not.
+//!
+//! Inflector behaves differently from both this crate and
+//! [heck](super::heck), for example it keeps acronyms together
+//! when converting to snake case, but merges them with the next word
+//! when converting to camel case, and starts a new word after digits.
+//!
+//! # Examples
+//!
+//! ```
+//! use naming_lib::compat::inflector::Inflector;
+//!
+//! assert_eq!("foo_bar", "FooBar".to_snake_case());
+//! assert_eq!("http_server", "HTTPServer".to_snake_case());
+//! assert_eq!("httpserver", "HTTPServer".to_camel_case());
+//! assert_eq!("foo1Bar", "foo1bar".to_camel_case());
+//! assert!("fooBar".is_camel_case());
+//! ```
+
+/// How a camel-like conversion composes words,
+/// same as `CamelOptions` in Inflector.
+struct CamelOptions {
+    new_word: bool,
+    first_word: bool,
+    injectable_char: char,
+    has_separator: bool,
+    inverted: bool,
+}
+
+const CAMEL: CamelOptions = CamelOptions {
+    new_word: false,
+    first_word: false,
+    injectable_char: ' ',
+    has_separator: false,
+    inverted: false,
+};
+
+const PASCAL: CamelOptions = CamelOptions { new_word: true, ..CAMEL };
+
+const TRAIN: CamelOptions = CamelOptions {
+    new_word: true,
+    first_word: true,
+    injectable_char: '-',
+    has_separator: true,
+    inverted: false,
+};
+
+const TITLE: CamelOptions = CamelOptions { injectable_char: ' ', ..TRAIN };
+
+const SENTENCE: CamelOptions = CamelOptions { inverted: true, ..TITLE };
+
+fn is_not_alphanumeric(c: char) -> bool {
+    !c.is_alphanumeric()
+}
+
+fn trim_right(s: &str) -> &str {
+    s.trim_end_matches(is_not_alphanumeric)
+}
+
+fn to_case_camel_like(s: &str, options: &CamelOptions) -> String {
+    let mut new_word = options.new_word;
+    let mut first_word = options.first_word;
+    let mut last_char = ' ';
+    let mut found_real_char = false;
+    let mut result = String::with_capacity(s.len() * 2);
+
+    for c in trim_right(s).chars() {
+        if is_not_alphanumeric(c) && found_real_char {
+            new_word = true;
+        } else if !found_real_char && is_not_alphanumeric(c) {
+            continue;
+        } else if c.is_numeric() {
+            found_real_char = true;
+            new_word = true;
+            result.push(c);
+        } else if new_word || (last_char.is_lowercase() && c.is_uppercase()) {
+            found_real_char = true;
+            new_word = false;
+            if options.has_separator && !first_word {
+                result.push(options.injectable_char);
+            }
+            if !options.inverted || first_word {
+                result.push(c.to_ascii_uppercase());
+            } else {
+                result.push(c.to_ascii_lowercase());
+            }
+            first_word = false;
+        } else {
+            found_real_char = true;
+            last_char = c;
+            result.push(c.to_ascii_lowercase());
+        }
+    }
+
+    result
+}
+
+fn to_case_snake_like(s: &str, separator: char, upper: bool) -> String {
+    let mut first_character = true;
+    let mut result = String::with_capacity(s.len() * 2);
+
+    for (i, c) in trim_right(s).char_indices() {
+        if is_not_alphanumeric(c) {
+            if !first_character {
+                first_character = true;
+                result.push(separator);
+            }
+            continue;
+        }
+
+        if requires_separator(s, i, c, first_character) {
+            result.push(separator);
+        }
+        first_character = false;
+        result.push(if upper {
+            c.to_ascii_uppercase()
+        } else {
+            c.to_ascii_lowercase()
+        });
+    }
+
+    result
+}
+
+/// Inflector looks up neighbours with the byte index as a char index,
+/// kept as is to produce the same outputs for non-ASCII strings.
+fn requires_separator(
+    s: &str,
+    i: usize,
+    c: char,
+    first_character: bool,
+) -> bool {
+    let neighbour_is_lowercase =
+        |index: usize| s.chars().nth(index).unwrap_or('A').is_lowercase();
+
+    !first_character
+        && c == c.to_ascii_uppercase()
+        && (neighbour_is_lowercase(i + 1) || neighbour_is_lowercase(i - 1))
+}
+
+/// Same as the case conversion methods of `inflector::Inflector`.
+pub trait Inflector {
+    /// Convert this string to camel case.
+    fn to_camel_case(&self) -> String;
+    /// Check if this string is already in camel case.
+    fn is_camel_case(&self) -> bool;
+
+    /// Convert this string to pascal case.
+    fn to_pascal_case(&self) -> String;
+    /// Check if this string is already in pascal case.
+    fn is_pascal_case(&self) -> bool;
+
+    /// Convert this string to snake case.
+    fn to_snake_case(&self) -> String;
+    /// Check if this string is already in snake case.
+    fn is_snake_case(&self) -> bool;
+
+    /// Convert this string to screaming snake case.
+    fn to_screaming_snake_case(&self) -> String;
+    /// Check if this string is already in screaming snake case.
+    fn is_screaming_snake_case(&self) -> bool;
+
+    /// Convert this string to kebab case.
+    fn to_kebab_case(&self) -> String;
+    /// Check if this string is already in kebab case.
+    fn is_kebab_case(&self) -> bool;
+
+    /// Convert this string to train case.
+    fn to_train_case(&self) -> String;
+    /// Check if this string is already in train case.
+    fn is_train_case(&self) -> bool;
+
+    /// Convert this string to sentence case.
+    fn to_sentence_case(&self) -> String;
+    /// Check if this string is already in sentence case.
+    fn is_sentence_case(&self) -> bool;
+
+    /// Convert this string to title case.
+    fn to_title_case(&self) -> String;
+    /// Check if this string is already in title case.
+    fn is_title_case(&self) -> bool;
+}
+
+impl Inflector for str {
+    fn to_camel_case(&self) -> String {
+        to_case_camel_like(self, &CAMEL)
+    }
+
+    fn is_camel_case(&self) -> bool {
+        self.to_camel_case() == self
+    }
+
+    fn to_pascal_case(&self) -> String {
+        to_case_camel_like(self, &PASCAL)
+    }
+
+    fn is_pascal_case(&self) -> bool {
+        self.to_pascal_case() == self
+    }
+
+    fn to_snake_case(&self) -> String {
+        to_case_snake_like(self, '_', false)
+    }
+
+    fn is_snake_case(&self) -> bool {
+        self.to_snake_case() == self
+    }
+
+    fn to_screaming_snake_case(&self) -> String {
+        to_case_snake_like(self, '_', true)
+    }
+
+    fn is_screaming_snake_case(&self) -> bool {
+        self.to_screaming_snake_case() == self
+    }
+
+    fn to_kebab_case(&self) -> String {
+        to_case_snake_like(self, '-', false)
+    }
+
+    fn is_kebab_case(&self) -> bool {
+        self.to_kebab_case() == self
+    }
+
+    fn to_train_case(&self) -> String {
+        to_case_camel_like(self, &TRAIN)
+    }
+
+    fn is_train_case(&self) -> bool {
+        self.to_train_case() == self
+    }
+
+    fn to_sentence_case(&self) -> String {
+        to_case_camel_like(self, &SENTENCE)
+    }
+
+    fn is_sentence_case(&self) -> bool {
+        self.to_sentence_case() == self
+    }
+
+    fn to_title_case(&self) -> String {
+        to_case_camel_like(self, &TITLE)
+    }
+
+    fn is_title_case(&self) -> bool {
+        self.to_title_case() == self
+    }
+}
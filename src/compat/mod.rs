@@ -0,0 +1,10 @@
+//! Reproduce the renaming behaviour of other crates,
+//! for code that has to agree with them byte-for-byte.
+//!
+//! These rules don't use the detectors of this crate,
+//! so their outputs may be invalid formats in this crate's view,
+//! for example `h_t_t_p_server` converted by serde from `HTTPServer`.
+
+pub mod heck;
+pub mod inflector;
+pub mod serde;
@@ -0,0 +1,182 @@
+//! serde's `rename_all` rules.
+//!
+//! serde derive renames Rust field names (snake case)
+//! and variant names (pascal case) with character-level rules,
+//! which differ from conversions of [NamingCase](crate::NamingCase).
+//! [RenameRule] reproduces them, so code generators can predict
+//! the names that serde derive will emit.
+//!
+//! # Examples
+//!
+//! ```
+//! use naming_lib::compat::serde::RenameRule;
+//! use naming_lib::from;
+//!
+//! let rule: RenameRule = "camelCase".parse().unwrap();
+//!
+//! assert_eq!("hTTPServer", rule.apply_to_variant("HTTPServer"));
+//! assert_eq!("version2", rule.apply_to_field("version_2"));
+//!
+//! // "version_2" isn't a valid snake case in this crate.
+//! assert!(from("version_2").to_camel().is_err());
+//! ```
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// A value of serde's `rename_all` attribute.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum RenameRule {
+    /// `"lowercase"`
+    LowerCase,
+    /// `"UPPERCASE"`
+    UpperCase,
+    /// `"PascalCase"`
+    PascalCase,
+    /// `"camelCase"`
+    CamelCase,
+    /// `"snake_case"`
+    SnakeCase,
+    /// `"SCREAMING_SNAKE_CASE"`
+    ScreamingSnakeCase,
+    /// `"kebab-case"`
+    KebabCase,
+    /// `"SCREAMING-KEBAB-CASE"`
+    ScreamingKebabCase,
+}
+
+const RENAME_RULES: [(&str, RenameRule); 8] = [
+    ("lowercase", RenameRule::LowerCase),
+    ("UPPERCASE", RenameRule::UpperCase),
+    ("PascalCase", RenameRule::PascalCase),
+    ("camelCase", RenameRule::CamelCase),
+    ("snake_case", RenameRule::SnakeCase),
+    ("SCREAMING_SNAKE_CASE", RenameRule::ScreamingSnakeCase),
+    ("kebab-case", RenameRule::KebabCase),
+    ("SCREAMING-KEBAB-CASE", RenameRule::ScreamingKebabCase),
+];
+
+impl RenameRule {
+    /// The attribute value of this rule, e.g. `"camelCase"`.
+    pub fn name(self) -> &'static str {
+        RENAME_RULES.iter().find(|(_, rule)| *rule == self).unwrap().0
+    }
+
+    /// Rename an enum variant, which is expected to be in pascal case,
+    /// like `#[serde(rename_all = "...")]` on an enum does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use naming_lib::compat::serde::RenameRule;
+    ///
+    /// assert_eq!("very-tasty", RenameRule::KebabCase.apply_to_variant("VeryTasty"));
+    /// assert_eq!("vERYTasty", RenameRule::CamelCase.apply_to_variant("VERYTasty"));
+    /// ```
+    pub fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            RenameRule::PascalCase => variant.to_owned(),
+            RenameRule::LowerCase => variant.to_ascii_lowercase(),
+            RenameRule::UpperCase => variant.to_ascii_uppercase(),
+            RenameRule::CamelCase => lowercase_first_char(variant),
+            RenameRule::SnakeCase => {
+                let mut snake = String::new();
+                for (i, ch) in variant.char_indices() {
+                    if i > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(ch.to_ascii_lowercase());
+                }
+                snake
+            }
+            RenameRule::ScreamingSnakeCase => RenameRule::SnakeCase
+                .apply_to_variant(variant)
+                .to_ascii_uppercase(),
+            RenameRule::KebabCase => RenameRule::SnakeCase
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+            RenameRule::ScreamingKebabCase => RenameRule::ScreamingSnakeCase
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+        }
+    }
+
+    /// Rename a struct field, which is expected to be in snake case,
+    /// like `#[serde(rename_all = "...")]` on a struct does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use naming_lib::compat::serde::RenameRule;
+    ///
+    /// assert_eq!("veryTasty", RenameRule::CamelCase.apply_to_field("very_tasty"));
+    /// assert_eq!("Http2Server", RenameRule::PascalCase.apply_to_field("http2__server"));
+    /// ```
+    pub fn apply_to_field(self, field: &str) -> String {
+        match self {
+            RenameRule::LowerCase | RenameRule::SnakeCase => field.to_owned(),
+            RenameRule::UpperCase => field.to_ascii_uppercase(),
+            RenameRule::PascalCase => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            RenameRule::CamelCase => lowercase_first_char(
+                &RenameRule::PascalCase.apply_to_field(field),
+            ),
+            RenameRule::ScreamingSnakeCase => field.to_ascii_uppercase(),
+            RenameRule::KebabCase => field.replace('_', "-"),
+            RenameRule::ScreamingKebabCase => RenameRule::ScreamingSnakeCase
+                .apply_to_field(field)
+                .replace('_', "-"),
+        }
+    }
+}
+
+impl Display for RenameRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for RenameRule {
+    type Err = &'static str;
+
+    /// Parse a `rename_all` attribute value, which is case sensitive.
+    ///
+    /// # Errors
+    ///
+    /// Get an [Err](core::result::Result::Err) for unknown values.
+    fn from_str(rename_all: &str) -> Result<Self, Self::Err> {
+        RENAME_RULES
+            .iter()
+            .find(|(name, _)| *name == rename_all)
+            .map(|(_, rule)| *rule)
+            .ok_or(
+                "Unknown rename rule, expected one of \
+                \"lowercase\", \"UPPERCASE\", \"PascalCase\", \"camelCase\", \
+                \"snake_case\", \"SCREAMING_SNAKE_CASE\", \"kebab-case\", \
+                \"SCREAMING-KEBAB-CASE\".",
+            )
+    }
+}
+
+/// serde slices off the first byte,
+/// which is the same for ASCII and won't panic on others.
+fn lowercase_first_char(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
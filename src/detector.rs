@@ -1,7 +1,9 @@
+use std::borrow::Cow;
+
 use lazy_static::lazy_static;
 use regex::Regex;
 
-use crate::NamingCase;
+use crate::{CaseKind, NamingCase};
 
 /// Determine which format the identifier belongs to.
 /// Alias of [NamingCase::new()] and [from()](crate::naming_case::from()).
@@ -13,43 +15,47 @@ use crate::NamingCase;
 /// and words are compose into various formats with symbol `"-"`, `"_"` or without symbol.
 ///
 /// ```
-/// use naming_lib::{NamingCase::*, which_case};
+/// use naming_lib::{CaseKind::*, which_case};
 ///
-/// assert_eq!(SingleWord("foo".to_string()), which_case("foo"));
-/// assert_eq!(SingleWord("foo123".to_string()), which_case("foo123"));
-/// assert_eq!(ScreamingSnake("FOO_BAR".to_string()), which_case("FOO_BAR"));
-/// assert_eq!(Snake("foo_bar".to_string()), which_case("foo_bar"));
-/// assert_eq!(Camel("fooBar".to_string()), which_case("fooBar"));
-/// assert_eq!(Pascal("FooBar".to_string()), which_case("FooBar"));
+/// assert_eq!(SingleWord, which_case("foo").kind());
+/// assert_eq!(SingleWord, which_case("foo123").kind());
+/// assert_eq!(ScreamingSnake, which_case("FOO_BAR").kind());
+/// assert_eq!(Snake, which_case("foo_bar").kind());
+/// assert_eq!(Camel, which_case("fooBar").kind());
+/// assert_eq!(Pascal, which_case("FooBar").kind());
 /// ```
 ///
 /// Therefore, the following strings will be recognized as invalid format.
 ///
 /// ```
-/// use naming_lib::{NamingCase::Invalid, which_case};
+/// use naming_lib::{CaseKind::Invalid, which_case};
 ///
-/// assert_eq!(Invalid("非英语".to_string()), which_case("非英语"));
-/// assert_eq!(Invalid("foo@bar".to_string()), which_case("foo@bar"));
-/// assert_eq!(Invalid("@foobar".to_string()), which_case("@foobar"));
-/// assert_eq!(Invalid("foobar@".to_string()), which_case("foobar@"));
+/// assert_eq!(Invalid, which_case("非英语").kind());
+/// assert_eq!(Invalid, which_case("foo@bar").kind());
+/// assert_eq!(Invalid, which_case("@foobar").kind());
+/// assert_eq!(Invalid, which_case("foobar@").kind());
 /// ```
-pub fn which_case(identifier: &str) -> NamingCase {
+///
+/// The returned value borrows the identifier without copying it,
+/// call [into_owned()](NamingCase::into_owned()) to keep it longer.
+pub fn which_case(identifier: &str) -> NamingCase<'_> {
     // Any better idea to refactor this method?
-    if is_single_word(identifier) {
-        NamingCase::SingleWord(identifier.to_string())
+    let kind = if is_single_word(identifier) {
+        CaseKind::SingleWord
     } else if is_screaming_snake(identifier) {
-        NamingCase::ScreamingSnake(identifier.to_string())
+        CaseKind::ScreamingSnake
     } else if is_snake(identifier) {
-        NamingCase::Snake(identifier.to_string())
+        CaseKind::Snake
     } else if is_kebab(identifier) {
-        NamingCase::Kebab(identifier.to_string())
+        CaseKind::Kebab
     } else if is_camel(identifier) {
-        NamingCase::Camel(identifier.to_string())
+        CaseKind::Camel
     } else if is_pascal(identifier) {
-        NamingCase::Pascal(identifier.to_string())
+        CaseKind::Pascal
     } else {
-        NamingCase::Invalid(identifier.to_string())
-    }
+        CaseKind::Invalid
+    };
+    NamingCase::with_kind(kind, Cow::Borrowed(identifier))
 }
 
 /// Matches `r"^(?:\[a-z]+|\[A-Z]+|\[A-Z]\[a-z]+)\d*$"`.
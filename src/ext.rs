@@ -0,0 +1,129 @@
+use std::borrow::Cow;
+
+use crate::{detector, NamingCase, TargetCase};
+
+/// Detect and convert naming formats directly on strings.
+///
+/// Implemented for [str], [String] and [Cow<str>](std::borrow::Cow).
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use naming_lib::NamingCaseExt;
+///
+/// assert!("fooBar".is_camel_case());
+/// assert_eq!("foo_bar", "fooBar".to_snake_case().unwrap());
+///
+/// // An identifier already in the target case is borrowed, not copied.
+/// assert!(matches!("foo_bar".to_snake_case(), Ok(Cow::Borrowed(_))));
+/// ```
+///
+/// # Errors
+///
+/// Conversion methods return an [Err](core::result::Result::Err)
+/// if the string is recognized as [Invalid](crate::CaseKind::Invalid),
+/// same as the conversion methods of [NamingCase].
+pub trait NamingCaseExt: AsRef<str> {
+    /// Determine which format the string belongs to,
+    /// same as [which_case()](crate::detector::which_case()).
+    fn naming_case(&self) -> NamingCase<'_> {
+        detector::which_case(self.as_ref())
+    }
+
+    /// Same as [is_single_word()](crate::detector::is_single_word()).
+    fn is_single_word(&self) -> bool {
+        detector::is_single_word(self.as_ref())
+    }
+
+    /// Same as [is_screaming_snake()](crate::detector::is_screaming_snake()).
+    fn is_screaming_snake_case(&self) -> bool {
+        detector::is_screaming_snake(self.as_ref())
+    }
+
+    /// Same as [is_snake()](crate::detector::is_snake()).
+    fn is_snake_case(&self) -> bool {
+        detector::is_snake(self.as_ref())
+    }
+
+    /// Same as [is_kebab()](crate::detector::is_kebab()).
+    fn is_kebab_case(&self) -> bool {
+        detector::is_kebab(self.as_ref())
+    }
+
+    /// Same as [is_camel()](crate::detector::is_camel()).
+    fn is_camel_case(&self) -> bool {
+        detector::is_camel(self.as_ref())
+    }
+
+    /// Same as [is_pascal()](crate::detector::is_pascal()).
+    fn is_pascal_case(&self) -> bool {
+        detector::is_pascal(self.as_ref())
+    }
+
+    /// Same as [to_screaming_snake()](NamingCase::to_screaming_snake()).
+    fn to_screaming_snake_case(&self) -> Result<Cow<'_, str>, &'static str> {
+        self.to_case(TargetCase::ScreamingSnake)
+    }
+
+    /// Same as [to_snake()](NamingCase::to_snake()).
+    fn to_snake_case(&self) -> Result<Cow<'_, str>, &'static str> {
+        self.to_case(TargetCase::Snake)
+    }
+
+    /// Same as [to_kebab()](NamingCase::to_kebab()).
+    fn to_kebab_case(&self) -> Result<Cow<'_, str>, &'static str> {
+        self.to_case(TargetCase::Kebab)
+    }
+
+    /// Same as [to_camel()](NamingCase::to_camel()).
+    fn to_camel_case(&self) -> Result<Cow<'_, str>, &'static str> {
+        self.to_case(TargetCase::Camel)
+    }
+
+    /// Same as [to_pascal()](NamingCase::to_pascal()).
+    fn to_pascal_case(&self) -> Result<Cow<'_, str>, &'static str> {
+        self.to_case(TargetCase::Pascal)
+    }
+
+    /// Same as [to_case()](NamingCase::to_case()).
+    fn to_case(
+        &self,
+        target: TargetCase,
+    ) -> Result<Cow<'_, str>, &'static str> {
+        let identifier = self.as_ref();
+        if is_already(identifier, target) {
+            return Ok(Cow::Borrowed(identifier));
+        }
+
+        let converted = detector::which_case(identifier).to_case(target)?;
+        if converted == identifier {
+            Ok(Cow::Borrowed(identifier))
+        } else {
+            Ok(Cow::Owned(converted))
+        }
+    }
+}
+
+impl NamingCaseExt for str {}
+
+impl NamingCaseExt for String {}
+
+impl NamingCaseExt for Cow<'_, str> {}
+
+/// Whether converting the identifier to the target case
+/// gives the identifier itself, checked without allocation.
+fn is_already(identifier: &str, target: TargetCase) -> bool {
+    match target {
+        TargetCase::ScreamingSnake => detector::is_screaming_snake(identifier),
+        TargetCase::Snake => detector::is_snake(identifier),
+        TargetCase::Kebab => detector::is_kebab(identifier),
+        TargetCase::Camel => detector::is_camel(identifier),
+        // "FOO" matches the pascal regex, but it's a single word
+        // and will be converted to "Foo".
+        TargetCase::Pascal => {
+            detector::is_pascal(identifier)
+                && !detector::is_screaming_snake(identifier)
+        }
+    }
+}
@@ -0,0 +1,351 @@
+//! Hungarian notation prefixes, recognized and generated by a dictionary.
+//!
+//! Unlike [from_hungarian_notation()](crate::from_hungarian_notation()),
+//! which always drops the first word of a camel case identifier,
+//! only prefixes in the dictionary are stripped here.
+//! Prefixes are generated from type hints with
+//! [to_hungarian()](crate::NamingCase::to_hungarian()).
+//!
+//! # Examples
+//!
+//! ```
+//! use naming_lib::hungarian::strip_prefix;
+//!
+//! let stripped = strip_prefix("szFileName");
+//! assert_eq!("sz", stripped.prefix.as_ref().unwrap().prefix);
+//! assert_eq!("zero-terminated string", stripped.prefix.unwrap().meaning);
+//! assert_eq!("FileName", stripped.name.as_str());
+//!
+//! // Not a known prefix, left untouched.
+//! let untouched = strip_prefix("isValid");
+//! assert_eq!(None, untouched.prefix);
+//! assert_eq!("isValid", untouched.name.as_str());
+//! ```
+
+use crate::{detector, CaseKind, NamingCase};
+
+/// A hungarian notation prefix and what it means.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Prefix {
+    pub prefix: String,
+    pub meaning: String,
+    /// Types that this prefix is generated for, like `char*` for `sz`.
+    pub type_hints: Vec<String>,
+}
+
+impl Prefix {
+    /// Create a prefix without type hints.
+    pub fn new(prefix: &str, meaning: &str) -> Prefix {
+        Prefix {
+            prefix: prefix.to_string(),
+            meaning: meaning.to_string(),
+            type_hints: Vec::new(),
+        }
+    }
+
+    /// Add types that this prefix is generated for.
+    pub fn with_type_hints(mut self, type_hints: &[&str]) -> Prefix {
+        self.type_hints.extend(type_hints.iter().map(|hint| normalize(hint)));
+        self
+    }
+}
+
+/// Type hints are compared with whitespaces collapsed,
+/// so `char *` is the same as `char*`.
+fn normalize(type_hint: &str) -> String {
+    type_hint
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .replace(" *", "*")
+}
+
+/// Result of stripping a prefix from an identifier.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Stripped<'a> {
+    /// The recognized prefix, [None] if nothing is stripped.
+    pub prefix: Option<Prefix>,
+    /// The rest of the identifier, or the whole one if nothing is stripped.
+    pub name: NamingCase<'a>,
+}
+
+/// Known prefixes, each one is a lowercase word.
+///
+/// The [default()](PrefixDictionary::default()) one
+/// holds [systems()](PrefixDictionary::systems()) prefixes.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PrefixDictionary {
+    prefixes: Vec<Prefix>,
+}
+
+// Prefix, meaning and the type hints that it is generated for.
+#[rustfmt::skip]
+const SYSTEMS: &[(&str, &str, &[&str])] = &[
+    ("a", "array", &[]),
+    ("b", "boolean", &["bool", "BOOL"]),
+    ("by", "byte", &["BYTE", "unsigned char", "uint8_t"]),
+    ("c", "char", &[]),
+    ("ch", "char", &["char", "CHAR"]),
+    ("d", "double", &["double"]),
+    ("dw", "DWORD (32-bit unsigned integer)", &["DWORD", "uint32_t"]),
+    ("f", "flag (boolean)", &[]),
+    ("fl", "float", &["float", "FLOAT"]),
+    ("fn", "function", &[]),
+    ("h", "handle", &["HANDLE", "HWND", "HINSTANCE"]),
+    ("i", "integer", &["int", "INT", "int32_t"]),
+    ("l", "long integer", &["long", "LONG"]),
+    ("lp", "long pointer", &["LPVOID"]),
+    ("lpfn", "long pointer to function", &[]),
+    ("lpsz", "long pointer to zero-terminated string", &["LPSTR", "LPCSTR", "LPTSTR", "LPCTSTR"]),
+    ("n", "integer", &[]),
+    ("p", "pointer", &["void*"]),
+    ("pfn", "pointer to function", &[]),
+    ("psz", "pointer to zero-terminated string", &["PSTR", "PCSTR"]),
+    ("str", "string", &["std::string", "string", "String", "CString"]),
+    ("sz", "zero-terminated string", &["char*", "const char*", "char[]"]),
+    ("u", "unsigned integer", &["unsigned", "unsigned int", "UINT"]),
+    ("ul", "unsigned long integer", &["unsigned long", "ULONG"]),
+    ("w", "WORD (16-bit unsigned integer)", &["WORD", "unsigned short", "uint16_t"]),
+];
+
+#[rustfmt::skip]
+const APPS: &[(&str, &str, &[&str])] = &[
+    ("c", "count", &[]),
+    ("cb", "count of bytes", &[]),
+    ("cch", "count of characters", &[]),
+    ("col", "column", &[]),
+    ("dx", "width", &[]),
+    ("dy", "height", &[]),
+    ("i", "index", &[]),
+    ("ix", "index", &[]),
+    ("max", "maximum", &[]),
+    ("min", "minimum", &[]),
+    ("rw", "row", &[]),
+    ("s", "safe string", &[]),
+    ("us", "unsafe string", &[]),
+    ("x", "horizontal coordinate", &[]),
+    ("y", "vertical coordinate", &[]),
+];
+
+impl PrefixDictionary {
+    /// Create an empty dictionary.
+    pub fn empty() -> PrefixDictionary {
+        PrefixDictionary { prefixes: Vec::new() }
+    }
+
+    /// Systems Hungarian prefixes that tell the data type,
+    /// like `sz` (zero-terminated string) or `dw` (DWORD).
+    pub fn systems() -> PrefixDictionary {
+        PrefixDictionary::from_table(SYSTEMS)
+    }
+
+    /// Apps Hungarian prefixes that tell the purpose,
+    /// like `cch` (count of characters) or `rw` (row).
+    pub fn apps() -> PrefixDictionary {
+        PrefixDictionary::from_table(APPS)
+    }
+
+    fn from_table(table: &[(&str, &str, &[&str])]) -> PrefixDictionary {
+        let mut dictionary = PrefixDictionary::empty();
+        for (prefix, meaning, type_hints) in table {
+            dictionary.insert(
+                Prefix::new(prefix, meaning).with_type_hints(type_hints),
+            );
+        }
+        dictionary
+    }
+
+    /// Add a prefix, or replace the meaning of a known one.
+    pub fn insert(&mut self, prefix: Prefix) {
+        match self.prefixes.iter_mut().find(|p| p.prefix == prefix.prefix) {
+            Some(known) => *known = prefix,
+            None => self.prefixes.push(prefix),
+        }
+    }
+
+    /// Forget a prefix, return it if it was known.
+    pub fn remove(&mut self, prefix: &str) -> Option<Prefix> {
+        let index = self.prefixes.iter().position(|p| p.prefix == prefix)?;
+        Some(self.prefixes.remove(index))
+    }
+
+    /// Look up a prefix.
+    pub fn get(&self, prefix: &str) -> Option<&Prefix> {
+        self.prefixes.iter().find(|p| p.prefix == prefix)
+    }
+
+    /// Find the prefix generated for a type,
+    /// the first one in the order they are added if there are many.
+    pub fn prefix_for_type(&self, type_hint: &str) -> Option<&Prefix> {
+        let type_hint = normalize(type_hint);
+        self.prefixes.iter().find(|p| p.type_hints.contains(&type_hint))
+    }
+
+    /// Get all known prefixes, in the order they are added.
+    pub fn prefixes(&self) -> &[Prefix] {
+        &self.prefixes
+    }
+
+    /// Strip the first word of a camel case identifier
+    /// if it is a known prefix.
+    ///
+    /// The rest is recognized with [which_case()](crate::which_case()),
+    /// so it's usually a pascal case or a single word.
+    /// Other identifiers are left untouched.
+    pub fn strip<'a>(&self, identifier: &'a str) -> Stripped<'a> {
+        let untouched = || Stripped {
+            prefix: None,
+            name: detector::which_case(identifier),
+        };
+
+        if detector::which_case(identifier).kind() != CaseKind::Camel {
+            return untouched();
+        }
+        let split = identifier
+            .find(|c: char| c.is_ascii_uppercase())
+            .unwrap_or(identifier.len());
+        let (first_word, rest) = identifier.split_at(split);
+
+        match self.get(first_word) {
+            Some(prefix) => Stripped {
+                prefix: Some(prefix.clone()),
+                name: detector::which_case(rest),
+            },
+            None => untouched(),
+        }
+    }
+}
+
+/// Same as [systems()](PrefixDictionary::systems()).
+impl Default for PrefixDictionary {
+    fn default() -> PrefixDictionary {
+        PrefixDictionary::systems()
+    }
+}
+
+/// Strip a Systems Hungarian prefix,
+/// same as [strip()](PrefixDictionary::strip())
+/// on the [default()](PrefixDictionary::default()) dictionary.
+pub fn strip_prefix(identifier: &str) -> Stripped<'_> {
+    PrefixDictionary::default().strip(identifier)
+}
+
+/// Prefixes that tell the scope of a variable.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ScopePrefix {
+    /// `m_`, class members.
+    Member,
+    /// `g_`, global variables.
+    Global,
+    /// `s_`, static variables.
+    Static,
+    /// `k`, constants in Google style, followed by a pascal case name.
+    Constant,
+    /// `_`, private members.
+    Underscore,
+}
+
+impl ScopePrefix {
+    /// Get the prefix as written in identifiers.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ScopePrefix::Member => "m_",
+            ScopePrefix::Global => "g_",
+            ScopePrefix::Static => "s_",
+            ScopePrefix::Constant => "k",
+            ScopePrefix::Underscore => "_",
+        }
+    }
+}
+
+/// Result of [parse_scope()].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Scoped<'a> {
+    /// The scope prefix, [None] if there isn't one.
+    pub scope: Option<ScopePrefix>,
+    /// The rest of the identifier, or the whole one without a scope prefix.
+    pub name: NamingCase<'a>,
+}
+
+impl Scoped<'_> {
+    /// Write the name with another scope prefix, or without one.
+    ///
+    /// The name is kept as is, except for the [Constant](ScopePrefix::Constant)
+    /// prefix which is followed by the name in pascal case.
+    ///
+    /// # Errors
+    ///
+    /// Get an [Err](core::result::Result::Err)
+    /// if the name needs to be converted but is
+    /// [Invalid](crate::CaseKind::Invalid).
+    pub fn with_scope(
+        &self,
+        scope: Option<ScopePrefix>,
+    ) -> Result<String, &'static str> {
+        match scope {
+            Some(ScopePrefix::Constant) => {
+                Ok(format!("k{}", self.name.to_pascal()?))
+            }
+            Some(scope) => Ok(format!("{}{}", scope.as_str(), self.name)),
+            None => Ok(self.name.to_string()),
+        }
+    }
+}
+
+/// Separate the scope prefix from an identifier,
+/// the rest is recognized with [which_case()](crate::which_case()).
+///
+/// A prefix is only separated if the rest is a valid identifier,
+/// so `key` or `m_` are not treated as prefixed.
+/// Type prefixes after the scope prefix, like `m_pszName`,
+/// can be stripped from the rest with [strip()](PrefixDictionary::strip()).
+///
+/// # Examples
+///
+/// ```
+/// use naming_lib::hungarian::{parse_scope, ScopePrefix};
+/// use naming_lib::CaseKind;
+///
+/// let member = parse_scope("m_userName");
+/// assert_eq!(Some(ScopePrefix::Member), member.scope);
+/// assert_eq!(CaseKind::Camel, member.name.kind());
+///
+/// let constant = parse_scope("kMaxSize");
+/// assert_eq!(Some(ScopePrefix::Constant), constant.scope);
+/// assert_eq!("MaxSize", constant.name.as_str());
+///
+/// assert_eq!("_userName", member.with_scope(Some(ScopePrefix::Underscore)).unwrap());
+/// assert_eq!("kUserName", member.with_scope(Some(ScopePrefix::Constant)).unwrap());
+/// assert_eq!("userName", member.with_scope(None).unwrap());
+/// ```
+pub fn parse_scope(identifier: &str) -> Scoped<'_> {
+    let is_valid_rest = |rest: &str| {
+        !rest.starts_with('_') && !detector::which_case(rest).is_invalid()
+    };
+
+    let scope = [
+        ScopePrefix::Member,
+        ScopePrefix::Global,
+        ScopePrefix::Static,
+        ScopePrefix::Underscore,
+    ]
+    .iter()
+    .copied()
+    .find(|scope| {
+        identifier.strip_prefix(scope.as_str()).is_some_and(is_valid_rest)
+    })
+    .or_else(|| {
+        identifier
+            .strip_prefix('k')
+            .filter(|rest| detector::is_pascal(rest))
+            .map(|_| ScopePrefix::Constant)
+    });
+
+    match scope {
+        Some(scope) => Scoped {
+            scope: Some(scope),
+            name: detector::which_case(&identifier[scope.as_str().len()..]),
+        },
+        None => Scoped { scope: None, name: detector::which_case(identifier) },
+    }
+}
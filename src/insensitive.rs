@@ -0,0 +1,84 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::hash::{Hash, Hasher};
+
+use crate::{detector, NamingCase};
+
+/// An identifier compared and hashed by its words,
+/// so the same name written in different formats is treated as equal.
+///
+/// Built on [normalization_key()](NamingCase::normalization_key()),
+/// [Invalid](crate::CaseKind::Invalid) identifiers have no key
+/// and are only equal to identical strings.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use naming_lib::CaseInsensitiveName;
+///
+/// let mut columns = HashMap::new();
+/// columns.insert(CaseInsensitiveName::new("userId"), 1);
+///
+/// assert_eq!(Some(&1), columns.get(&CaseInsensitiveName::new("user_id")));
+/// assert_eq!(Some(&1), columns.get(&CaseInsensitiveName::new("USER_ID")));
+/// assert_eq!(Some(&1), columns.get(&CaseInsensitiveName::new("user-id")));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CaseInsensitiveName<'a> {
+    name: NamingCase<'a>,
+    key: Key,
+}
+
+/// Keys of valid and invalid identifiers never equal to each other.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+enum Key {
+    Words(String),
+    Raw(String),
+}
+
+impl<'a> CaseInsensitiveName<'a> {
+    /// Create a value from an identifier.
+    pub fn new(identifier: &'a str) -> CaseInsensitiveName<'a> {
+        CaseInsensitiveName::from(detector::which_case(identifier))
+    }
+
+    /// Get the identifier as it was given.
+    pub fn as_naming_case(&self) -> &NamingCase<'a> {
+        &self.name
+    }
+
+    /// Take the identifier as it was given.
+    pub fn into_naming_case(self) -> NamingCase<'a> {
+        self.name
+    }
+}
+
+impl<'a> From<NamingCase<'a>> for CaseInsensitiveName<'a> {
+    fn from(name: NamingCase<'a>) -> CaseInsensitiveName<'a> {
+        let key = match name.normalization_key() {
+            Ok(words) => Key::Words(words),
+            Err(_) => Key::Raw(name.as_str().to_string()),
+        };
+        CaseInsensitiveName { name, key }
+    }
+}
+
+impl PartialEq for CaseInsensitiveName<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for CaseInsensitiveName<'_> {}
+
+impl Hash for CaseInsensitiveName<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key.hash(state)
+    }
+}
+
+impl Display for CaseInsensitiveName<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.name)
+    }
+}
@@ -0,0 +1,453 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+use super::{is_go_exported, Language};
+use crate::naming_case::compose_words;
+use crate::{detector, TargetCase};
+
+/// Kinds of named symbols in source code.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum SymbolKind {
+    /// Structs, classes, enums, traits and interfaces.
+    Type,
+    EnumVariant,
+    /// Free functions.
+    Function,
+    Method,
+    /// Local variables.
+    Variable,
+    Parameter,
+    /// Struct fields and class properties.
+    Field,
+    Constant,
+    /// Static variables that are not constants.
+    Static,
+    /// Modules, packages and namespaces.
+    Module,
+}
+
+impl SymbolKind {
+    /// All kinds of symbols.
+    pub const ALL: [SymbolKind; 10] = [
+        SymbolKind::Type,
+        SymbolKind::EnumVariant,
+        SymbolKind::Function,
+        SymbolKind::Method,
+        SymbolKind::Variable,
+        SymbolKind::Parameter,
+        SymbolKind::Field,
+        SymbolKind::Constant,
+        SymbolKind::Static,
+        SymbolKind::Module,
+    ];
+
+    /// Get the kebab case name of this kind,
+    /// which can be parsed back by [from_str()](SymbolKind::from_str()).
+    pub fn name(self) -> &'static str {
+        match self {
+            SymbolKind::Type => "type",
+            SymbolKind::EnumVariant => "enum-variant",
+            SymbolKind::Function => "function",
+            SymbolKind::Method => "method",
+            SymbolKind::Variable => "variable",
+            SymbolKind::Parameter => "parameter",
+            SymbolKind::Field => "field",
+            SymbolKind::Constant => "constant",
+            SymbolKind::Static => "static",
+            SymbolKind::Module => "module",
+        }
+    }
+}
+
+impl Display for SymbolKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for SymbolKind {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SymbolKind::ALL
+            .iter()
+            .copied()
+            .find(|kind| kind.name() == s)
+            .ok_or("Unknown symbol kind name.")
+    }
+}
+
+/// Which case a kind of symbol should be written in.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Convention {
+    Case(TargetCase),
+    /// Go decides the visibility by the first letter,
+    /// exported names are in pascal case, others in camel case.
+    GoVisibility,
+}
+
+impl Convention {
+    /// Get the expected case of the identifier.
+    pub fn case_for(self, identifier: &str) -> TargetCase {
+        match self {
+            Convention::Case(case) => case,
+            Convention::GoVisibility if is_go_exported(identifier) => {
+                TargetCase::Pascal
+            }
+            Convention::GoVisibility => TargetCase::Camel,
+        }
+    }
+}
+
+/// How acronyms are written in camel and pascal case.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum AcronymPolicy {
+    /// Acronyms may stay uppercase, like `HTTPServer` or `userID`,
+    /// as well as be capitalized like other words.
+    #[default]
+    Preserve,
+    /// Acronyms are capitalized like other words, like `HttpServer`.
+    Capitalize,
+}
+
+impl AcronymPolicy {
+    /// Get the name used in config files, "preserve" or "capitalize".
+    pub fn name(self) -> &'static str {
+        match self {
+            AcronymPolicy::Preserve => "preserve",
+            AcronymPolicy::Capitalize => "capitalize",
+        }
+    }
+}
+
+impl FromStr for AcronymPolicy {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" => Ok(AcronymPolicy::Preserve),
+            "capitalize" => Ok(AcronymPolicy::Capitalize),
+            _ => Err("Unknown acronym policy name."),
+        }
+    }
+}
+
+/// How [check_with()] tells words apart.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct CheckOptions<'a> {
+    /// Default is [Preserve](AcronymPolicy::Preserve).
+    pub acronyms: AcronymPolicy,
+    /// Words that keep their spelling in every case, like `iOS` or `macOS`.
+    pub exceptions: &'a [String],
+}
+
+/// An identifier that breaks the convention of its language.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Violation {
+    /// The case it should be written in.
+    pub expected: TargetCase,
+    /// The identifier converted to the expected case
+    /// with [to_case()](crate::NamingCase::to_case()),
+    /// or [to_case_lossy()](crate::NamingCase::to_case_lossy()) if it's invalid,
+    /// with acronyms and exceptions kept as [CheckOptions] say,
+    /// keywords escaped and leading or trailing underscores kept.
+    /// [None] if there isn't any word in it.
+    pub suggestion: Option<String>,
+}
+
+/// Get the case that a kind of symbol should be written in,
+/// as the common style guide of the language says
+/// (Rust API guidelines, PEP 8, Effective Go and so on).
+///
+/// Return [None] if the language has no convention for this kind,
+/// like Java packages which are lowercase without separators.
+///
+/// # Examples
+///
+/// ```
+/// use naming_lib::language::{expected_case, Convention, Language, SymbolKind};
+/// use naming_lib::TargetCase;
+///
+/// let constant = expected_case(Language::Rust, SymbolKind::Constant);
+/// assert_eq!(Some(Convention::Case(TargetCase::ScreamingSnake)), constant);
+///
+/// let method = expected_case(Language::CSharp, SymbolKind::Method);
+/// assert_eq!(Some(Convention::Case(TargetCase::Pascal)), method);
+/// ```
+pub fn expected_case(
+    language: Language,
+    kind: SymbolKind,
+) -> Option<Convention> {
+    use SymbolKind::*;
+    use TargetCase::*;
+
+    let case = match (language, kind) {
+        (Language::Go, Module) => return None,
+        (Language::Go, _) => return Some(Convention::GoVisibility),
+
+        (Language::Rust, Type | EnumVariant) => Pascal,
+        (Language::Rust, Constant | Static) => ScreamingSnake,
+        (Language::Rust, _) => Snake,
+
+        (Language::Python, Type) => Pascal,
+        (Language::Python, EnumVariant | Constant) => ScreamingSnake,
+        (Language::Python, Static) => return None,
+        (Language::Python, _) => Snake,
+
+        (Language::Java | Language::Kotlin, Type) => Pascal,
+        (Language::Java | Language::Kotlin, EnumVariant | Constant) => {
+            ScreamingSnake
+        }
+        (Language::Java, Static) => Camel,
+        (Language::Java | Language::Kotlin, Static | Module) => return None,
+        (Language::Java | Language::Kotlin, _) => Camel,
+
+        (Language::CSharp, Variable | Parameter | Field) => Camel,
+        (Language::CSharp, _) => Pascal,
+
+        (Language::Swift, Type | Module) => Pascal,
+        (Language::Swift, _) => Camel,
+
+        (Language::JavaScript | Language::TypeScript, Type | EnumVariant) => {
+            Pascal
+        }
+        (Language::JavaScript | Language::TypeScript, Constant) => {
+            ScreamingSnake
+        }
+        (Language::JavaScript | Language::TypeScript, Module) => return None,
+        (Language::JavaScript | Language::TypeScript, _) => Camel,
+
+        (Language::C | Language::Cpp | Language::Sql, _) => return None,
+    };
+    Some(Convention::Case(case))
+}
+
+/// Check if an identifier follows the convention of its language,
+/// see [expected_case()].
+///
+/// Leading and trailing underscores are ignored,
+/// so are the conventions of private names (`_private`),
+/// Python dunder names (`__init__`) and escaped keywords (`type_`).
+/// Acronyms may stay uppercase, like `userID`,
+/// see [check_with()] for other policies.
+///
+/// # Examples
+///
+/// ```
+/// use naming_lib::language::{check, Language, SymbolKind};
+/// use naming_lib::TargetCase;
+///
+/// assert!(check(Language::Rust, SymbolKind::Function, "parse_args").is_ok());
+/// assert!(check(Language::Go, SymbolKind::Function, "ParseArgs").is_ok());
+///
+/// let violation = check(Language::Rust, SymbolKind::Type, "http_client").unwrap_err();
+/// assert_eq!(TargetCase::Pascal, violation.expected);
+/// assert_eq!(Some("HttpClient".to_string()), violation.suggestion);
+///
+/// let violation = check(Language::Python, SymbolKind::Function, "_parseArgs").unwrap_err();
+/// assert_eq!(Some("_parse_args".to_string()), violation.suggestion);
+/// ```
+///
+/// # Errors
+///
+/// Get a [Violation] if the identifier isn't in the expected case.
+pub fn check(
+    language: Language,
+    kind: SymbolKind,
+    identifier: &str,
+) -> Result<(), Violation> {
+    match expected_case(language, kind) {
+        Some(convention) => check_with(
+            language,
+            convention,
+            identifier,
+            &CheckOptions::default(),
+        ),
+        None => Ok(()),
+    }
+}
+
+/// Check if an identifier follows the given convention,
+/// like [check()] but without looking up [expected_case()],
+/// and with words told apart by the given options.
+///
+/// # Examples
+///
+/// ```
+/// use naming_lib::language::{
+///     check_with, AcronymPolicy, CheckOptions, Convention, Language,
+/// };
+/// use naming_lib::TargetCase;
+///
+/// let pascal = Convention::Case(TargetCase::Pascal);
+/// let snake = Convention::Case(TargetCase::Snake);
+/// let mut options = CheckOptions::default();
+/// assert!(check_with(Language::Java, pascal, "HTTPServer", &options).is_ok());
+///
+/// options.acronyms = AcronymPolicy::Capitalize;
+/// let violation = check_with(Language::Java, pascal, "HTTPServer", &options);
+/// assert_eq!(Some("HttpServer".to_string()), violation.unwrap_err().suggestion);
+///
+/// let exceptions = ["iOS".to_string()];
+/// options.exceptions = &exceptions;
+/// assert!(check_with(Language::Rust, snake, "is_iOS_app", &options).is_ok());
+/// ```
+///
+/// # Errors
+///
+/// Get a [Violation] if the identifier isn't in the expected case.
+pub fn check_with(
+    language: Language,
+    convention: Convention,
+    identifier: &str,
+    options: &CheckOptions<'_>,
+) -> Result<(), Violation> {
+    let core = identifier.trim_matches('_');
+    if core.is_empty() || options.exceptions.iter().any(|e| e == core) {
+        return Ok(());
+    }
+
+    let expected = convention.case_for(core);
+    let words = words_of(core, options.exceptions);
+    let converted = if words.iter().all(|word| word.kind == WordKind::Plain) {
+        let case = detector::which_case(core);
+        case.to_case(expected).or_else(|_| case.to_case_lossy(expected)).ok()
+    } else {
+        Some(compose(words.clone(), expected, options.acronyms))
+    };
+    if converted.as_deref() == Some(core) {
+        return Ok(());
+    }
+
+    let suggestion = converted.map(|converted| {
+        let start = identifier.len() - identifier.trim_start_matches('_').len();
+        let end = identifier.trim_end_matches('_').len();
+        // Exceptions are never keywords.
+        let escaped = if words.iter().any(|w| w.kind == WordKind::Exception) {
+            converted.into()
+        } else {
+            language.escape(&converted)
+        };
+        format!("{}{}{}", &identifier[..start], escaped, &identifier[end..])
+    });
+    Err(Violation { expected, suggestion })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum WordKind {
+    Plain,
+    /// Single uppercase letters written together, like `ID` in `userID`.
+    Acronym,
+    /// Spelled as it is in every case, like `iOS`.
+    Exception,
+}
+
+#[derive(Clone, Debug)]
+struct Word {
+    text: String,
+    kind: WordKind,
+}
+
+/// Get words of an identifier as [NamingCase](crate::NamingCase) sees them,
+/// with exceptions cut out first and acronyms put back together.
+fn words_of(core: &str, exceptions: &[String]) -> Vec<Word> {
+    let mut words = Vec::new();
+    let mut plain_start = 0;
+    let mut i = 0;
+    while i < core.len() {
+        match exception_at(&core[i..], exceptions) {
+            Some(len) => {
+                push_words_of(&core[plain_start..i], &mut words);
+                words.push(Word {
+                    text: core[i..i + len].to_string(),
+                    kind: WordKind::Exception,
+                });
+                i += len;
+                plain_start = i;
+            }
+            None => i += core[i..].chars().next().unwrap().len_utf8(),
+        }
+    }
+    push_words_of(&core[plain_start..], &mut words);
+    words
+}
+
+/// Get the length of the longest exception at the start of the text,
+/// if it isn't followed by lowercase letters.
+fn exception_at(text: &str, exceptions: &[String]) -> Option<usize> {
+    exceptions
+        .iter()
+        .filter(|exception| {
+            !exception.is_empty()
+                && text
+                    .strip_prefix(exception.as_str())
+                    .is_some_and(|after| !after.starts_with(char::is_lowercase))
+        })
+        .map(String::len)
+        .max()
+}
+
+/// Push words of a part without exceptions,
+/// single uppercase letters written next to each other become an acronym,
+/// like `H`, `T`, `T`, `P` in `HTTPServer`.
+fn push_words_of(part: &str, words: &mut Vec<Word>) {
+    let is_letter = |word: &str| {
+        word.starts_with(|c: char| c.is_ascii_uppercase())
+            && word[1..].chars().all(|c| c.is_ascii_digit())
+    };
+
+    let mut cursor = 0;
+    // Where the last pushed word ends, if it is a single letter.
+    let mut letter_end = None;
+    for text in detector::which_case(part).words_lossy() {
+        let start = cursor + part[cursor..].find(text.as_str()).unwrap();
+        cursor = start + text.len();
+
+        if is_letter(&text) && letter_end == Some(start) {
+            let last = words.last_mut().unwrap();
+            last.text += &text;
+            last.kind = WordKind::Acronym;
+        } else {
+            words.push(Word { text: text.clone(), kind: WordKind::Plain });
+        }
+        letter_end = if is_letter(&text) { Some(cursor) } else { None };
+    }
+}
+
+/// Join words in the target case like [NamingCase](crate::NamingCase) does,
+/// except for exceptions, and acronyms if they are preserved.
+fn compose(
+    words: Vec<Word>,
+    target: TargetCase,
+    acronyms: AcronymPolicy,
+) -> String {
+    let separator = match target {
+        TargetCase::ScreamingSnake | TargetCase::Snake => "_",
+        TargetCase::Kebab => "-",
+        TargetCase::Camel | TargetCase::Pascal => "",
+    };
+    words
+        .into_iter()
+        .enumerate()
+        .map(|(i, word)| {
+            let capitalized = match target {
+                TargetCase::Camel => i > 0,
+                TargetCase::Pascal => true,
+                _ => false,
+            };
+            match word.kind {
+                WordKind::Exception => word.text,
+                WordKind::Acronym
+                    if capitalized && acronyms == AcronymPolicy::Preserve =>
+                {
+                    word.text
+                }
+                _ if capitalized => {
+                    compose_words(vec![word.text], TargetCase::Pascal)
+                }
+                _ => compose_words(vec![word.text], target),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(separator)
+}
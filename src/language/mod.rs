@@ -0,0 +1,247 @@
+//! Rules of identifiers in programming languages.
+//!
+//! # Examples
+//!
+//! ```
+//! use naming_lib::language::{Escape, Language};
+//! use naming_lib::{from, TargetCase};
+//!
+//! assert!(Language::Rust.is_keyword("type"));
+//! assert_eq!("r#type", Language::Rust.escape("type"));
+//! assert_eq!("type_", Language::Rust.escape_with("type", Escape::TrailingUnderscore));
+//! assert_eq!("@class", Language::CSharp.escape("class"));
+//! assert_eq!("\"order\"", Language::Sql.escape("order"));
+//!
+//! let converted = from("Def").to_case_escaped(TargetCase::Snake, Language::Python);
+//! assert_eq!("def_", converted.unwrap());
+//! ```
+
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+use crate::TargetCase;
+
+pub use conventions::*;
+pub use validity::*;
+
+mod conventions;
+mod keywords;
+mod validity;
+
+/// Programming languages with known identifier rules.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Language {
+    Rust,
+    Python,
+    Java,
+    Kotlin,
+    Go,
+    JavaScript,
+    TypeScript,
+    C,
+    Cpp,
+    CSharp,
+    Swift,
+    Sql,
+}
+
+/// How to turn a keyword into a legal identifier.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Escape {
+    /// Rust raw identifiers, like `r#type`.
+    RawIdentifier,
+    /// Like `type_`.
+    TrailingUnderscore,
+    /// C# verbatim identifiers, like `@class`.
+    AtSign,
+    /// Kotlin and Swift, like `` `class` ``.
+    Backticks,
+    /// SQL quoted identifiers, like `"order"`.
+    DoubleQuotes,
+}
+
+impl Language {
+    /// All known languages.
+    pub const ALL: [Language; 12] = [
+        Language::Rust,
+        Language::Python,
+        Language::Java,
+        Language::Kotlin,
+        Language::Go,
+        Language::JavaScript,
+        Language::TypeScript,
+        Language::C,
+        Language::Cpp,
+        Language::CSharp,
+        Language::Swift,
+        Language::Sql,
+    ];
+
+    /// Get the lowercase name of this language,
+    /// which can be parsed back by [from_str()](Language::from_str()).
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::Rust => "rust",
+            Language::Python => "python",
+            Language::Java => "java",
+            Language::Kotlin => "kotlin",
+            Language::Go => "go",
+            Language::JavaScript => "javascript",
+            Language::TypeScript => "typescript",
+            Language::C => "c",
+            Language::Cpp => "cpp",
+            Language::CSharp => "csharp",
+            Language::Swift => "swift",
+            Language::Sql => "sql",
+        }
+    }
+
+    /// Guess the language of a source file by its extension,
+    /// given without the leading dot.
+    ///
+    /// C headers (`.h`) are treated as C.
+    pub fn from_extension(extension: &str) -> Option<Language> {
+        match extension {
+            "rs" => Some(Language::Rust),
+            "py" | "pyi" => Some(Language::Python),
+            "java" => Some(Language::Java),
+            "kt" | "kts" => Some(Language::Kotlin),
+            "go" => Some(Language::Go),
+            "js" | "mjs" | "cjs" | "jsx" => Some(Language::JavaScript),
+            "ts" | "mts" | "cts" | "tsx" => Some(Language::TypeScript),
+            "c" | "h" => Some(Language::C),
+            "cpp" | "cc" | "cxx" | "hpp" | "hh" | "hxx" => Some(Language::Cpp),
+            "cs" => Some(Language::CSharp),
+            "swift" => Some(Language::Swift),
+            "sql" => Some(Language::Sql),
+            _ => None,
+        }
+    }
+
+    /// Get the case of local variables in this language,
+    /// used when there is no better choice.
+    pub fn preferred_case(self) -> TargetCase {
+        match self {
+            Language::Rust
+            | Language::Python
+            | Language::C
+            | Language::Cpp
+            | Language::Sql => TargetCase::Snake,
+            Language::Java
+            | Language::Kotlin
+            | Language::Go
+            | Language::JavaScript
+            | Language::TypeScript
+            | Language::CSharp
+            | Language::Swift => TargetCase::Camel,
+        }
+    }
+
+    /// Get reserved words of this language.
+    pub fn keywords(self) -> &'static [&'static str] {
+        match self {
+            Language::Rust => keywords::RUST,
+            Language::Python => keywords::PYTHON,
+            Language::Java => keywords::JAVA,
+            Language::Kotlin => keywords::KOTLIN,
+            Language::Go => keywords::GO,
+            Language::JavaScript => keywords::JAVASCRIPT,
+            Language::TypeScript => keywords::TYPESCRIPT,
+            Language::C => keywords::C,
+            Language::Cpp => keywords::CPP,
+            Language::CSharp => keywords::CSHARP,
+            Language::Swift => keywords::SWIFT,
+            Language::Sql => keywords::SQL,
+        }
+    }
+
+    /// Check if the identifier is a reserved word of this language.
+    ///
+    /// SQL keywords are compared case-insensitively.
+    pub fn is_keyword(self, identifier: &str) -> bool {
+        match self {
+            Language::Sql => self
+                .keywords()
+                .iter()
+                .any(|keyword| keyword.eq_ignore_ascii_case(identifier)),
+            _ => self.keywords().contains(&identifier),
+        }
+    }
+
+    /// Get the usual way to escape keywords in this language.
+    ///
+    /// Languages without an escape syntax append an underscore,
+    /// as PEP 8 recommends for Python.
+    pub fn default_escape(self) -> Escape {
+        match self {
+            Language::Rust => Escape::RawIdentifier,
+            Language::CSharp => Escape::AtSign,
+            Language::Kotlin | Language::Swift => Escape::Backticks,
+            Language::Sql => Escape::DoubleQuotes,
+            _ => Escape::TrailingUnderscore,
+        }
+    }
+
+    /// Escape the identifier with [default_escape()](Language::default_escape())
+    /// if it is a keyword, or borrow it back.
+    pub fn escape(self, identifier: &str) -> Cow<'_, str> {
+        self.escape_with(identifier, self.default_escape())
+    }
+
+    /// Escape the identifier in the given way if it is a keyword,
+    /// or borrow it back.
+    ///
+    /// Rust keywords that can't be raw identifiers
+    /// (`crate`, `self`, `Self` and `super`) get a trailing underscore.
+    pub fn escape_with(self, identifier: &str, escape: Escape) -> Cow<'_, str> {
+        if !self.is_keyword(identifier) {
+            return Cow::Borrowed(identifier);
+        }
+
+        let escaped = match escape {
+            Escape::RawIdentifier
+                if ["crate", "self", "Self", "super"].contains(&identifier) =>
+            {
+                format!("{}_", identifier)
+            }
+            Escape::RawIdentifier => format!("r#{}", identifier),
+            Escape::TrailingUnderscore => format!("{}_", identifier),
+            Escape::AtSign => format!("@{}", identifier),
+            Escape::Backticks => format!("`{}`", identifier),
+            Escape::DoubleQuotes => {
+                format!("\"{}\"", identifier.replace('"', "\"\""))
+            }
+        };
+        Cow::Owned(escaped)
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Language {
+    type Err = &'static str;
+
+    /// Parse a lowercase name, or a common alias like `js` or `c++`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rust" | "rs" => Ok(Language::Rust),
+            "python" | "py" => Ok(Language::Python),
+            "java" => Ok(Language::Java),
+            "kotlin" | "kt" => Ok(Language::Kotlin),
+            "go" | "golang" => Ok(Language::Go),
+            "javascript" | "js" => Ok(Language::JavaScript),
+            "typescript" | "ts" => Ok(Language::TypeScript),
+            "c" => Ok(Language::C),
+            "cpp" | "c++" => Ok(Language::Cpp),
+            "csharp" | "c#" | "cs" => Ok(Language::CSharp),
+            "swift" => Ok(Language::Swift),
+            "sql" => Ok(Language::Sql),
+            _ => Err("Unknown language name."),
+        }
+    }
+}
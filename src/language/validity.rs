@@ -0,0 +1,187 @@
+use std::borrow::Cow;
+
+use unicode_xid::UnicodeXID;
+
+use super::Language;
+use crate::detector;
+
+/// Identifiers longer than this are truncated by PostgreSQL,
+/// the strictest limit among major databases.
+pub const SQL_MAX_IDENTIFIER_LENGTH: usize = 63;
+
+/// Check if the identifier is legal in the language.
+///
+/// - Rust and Python follow the UAX #31 `XID_Start` / `XID_Continue` rules,
+///   so do Kotlin, Swift, C, C++ and C#, which also accept `_` as a start.
+/// - Java, JavaScript and TypeScript also accept `$`.
+/// - Go accepts Unicode letters, digits and `_`.
+/// - SQL accepts ASCII letters, digits and `_` for unquoted identifiers,
+///   up to [SQL_MAX_IDENTIFIER_LENGTH] characters.
+///
+/// A single `_` is only legal where it isn't reserved,
+/// which rules out Rust, Java, Kotlin and Swift.
+///
+/// Keywords are not legal unless escaped in the language's syntax,
+/// like `r#type` in Rust, `@class` in C#, `` `fun` `` in Kotlin and Swift,
+/// or `"order"` in SQL.
+///
+/// # Examples
+///
+/// ```
+/// use naming_lib::language::{is_valid_identifier, Language};
+///
+/// assert!(is_valid_identifier(Language::Rust, "café"));
+/// assert!(is_valid_identifier(Language::Rust, "r#type"));
+/// assert!(!is_valid_identifier(Language::Rust, "type"));
+/// assert!(is_valid_identifier(Language::JavaScript, "$el"));
+/// assert!(!is_valid_identifier(Language::Python, "$el"));
+/// assert!(!is_valid_identifier(Language::Go, "2fast"));
+/// ```
+pub fn is_valid_identifier(language: Language, identifier: &str) -> bool {
+    if language == Language::Sql && is_quoted_sql(identifier) {
+        return true;
+    }
+    if let Some(unescaped) = unescape(language, identifier) {
+        return is_legal_word(language, unescaped);
+    }
+    is_legal_word(language, identifier) && !language.is_keyword(identifier)
+}
+
+/// Strip the escape syntax of the language,
+/// return [None] if the identifier isn't escaped.
+fn unescape(language: Language, identifier: &str) -> Option<&str> {
+    match language {
+        Language::Rust => identifier
+            .strip_prefix("r#")
+            .filter(|s| !["crate", "self", "Self", "super", "_"].contains(s)),
+        Language::CSharp => identifier.strip_prefix('@'),
+        Language::Kotlin | Language::Swift => identifier
+            .strip_prefix('`')
+            .and_then(|s| s.strip_suffix('`'))
+            .filter(|s| !s.contains('`')),
+        _ => None,
+    }
+}
+
+/// Anything can be quoted in SQL, with inner quotes doubled.
+fn is_quoted_sql(identifier: &str) -> bool {
+    identifier.strip_prefix('"').and_then(|s| s.strip_suffix('"')).is_some_and(
+        |s| {
+            !s.is_empty()
+                && !s.replace("\"\"", "").contains('"')
+                && s.chars().count() <= SQL_MAX_IDENTIFIER_LENGTH
+        },
+    )
+}
+
+fn is_legal_word(language: Language, word: &str) -> bool {
+    let mut chars = word.chars();
+    let first = match chars.next() {
+        Some(c) => c,
+        None => return false,
+    };
+    // A single underscore is a reserved wildcard in these languages,
+    // elsewhere it's an ordinary (if unusual) name.
+    if word == "_"
+        && matches!(
+            language,
+            Language::Rust
+                | Language::Java
+                | Language::Kotlin
+                | Language::Swift
+        )
+    {
+        return false;
+    }
+
+    match language {
+        Language::Java | Language::JavaScript | Language::TypeScript => {
+            (first.is_xid_start() || first == '_' || first == '$')
+                && chars.all(|c| c.is_xid_continue() || c == '$')
+        }
+        Language::Go => {
+            (first.is_alphabetic() || first == '_')
+                && chars.all(|c| c.is_alphanumeric() || c == '_')
+        }
+        Language::Sql => {
+            (first.is_ascii_alphabetic() || first == '_')
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+                && word.len() <= SQL_MAX_IDENTIFIER_LENGTH
+        }
+        Language::Rust
+        | Language::Python
+        | Language::Kotlin
+        | Language::Swift
+        | Language::C
+        | Language::Cpp
+        | Language::CSharp => {
+            (first.is_xid_start() || first == '_')
+                && chars.all(UnicodeXID::is_xid_continue)
+        }
+    }
+}
+
+/// Check if a Go identifier is exported,
+/// which means it starts with an uppercase letter.
+///
+/// # Examples
+///
+/// ```
+/// use naming_lib::language::is_go_exported;
+///
+/// assert!(is_go_exported("Reader"));
+/// assert!(!is_go_exported("reader"));
+/// assert!(!is_go_exported("_Reader"));
+/// ```
+pub fn is_go_exported(identifier: &str) -> bool {
+    identifier.chars().next().is_some_and(char::is_uppercase)
+}
+
+/// Turn a string into the closest legal identifier of the language,
+/// or borrow it back if it is already legal.
+///
+/// Words are extracted with
+/// [to_case_lossy()](crate::NamingCase::to_case_lossy())
+/// and composed in the language's
+/// [preferred_case()](Language::preferred_case()),
+/// then an underscore is prepended if it starts with a digit,
+/// keywords are escaped with [escape()](Language::escape()),
+/// and SQL identifiers are truncated to [SQL_MAX_IDENTIFIER_LENGTH].
+///
+/// # Examples
+///
+/// ```
+/// use naming_lib::language::{sanitize_identifier, Language};
+///
+/// assert_eq!("user_id", sanitize_identifier(Language::Rust, "user id").unwrap());
+/// assert_eq!("userId", sanitize_identifier(Language::Java, "user-id").unwrap());
+/// assert_eq!("_2_fast", sanitize_identifier(Language::Python, "2 fast").unwrap());
+/// assert_eq!("r#type", sanitize_identifier(Language::Rust, "Type!").unwrap());
+/// assert_eq!("userId", sanitize_identifier(Language::Rust, "userId").unwrap());
+/// ```
+///
+/// # Errors
+///
+/// Get an [Err](core::result::Result::Err)
+/// if there isn't any word in the string.
+pub fn sanitize_identifier(
+    language: Language,
+    s: &str,
+) -> Result<Cow<'_, str>, &'static str> {
+    if is_valid_identifier(language, s) {
+        return Ok(Cow::Borrowed(s));
+    }
+
+    let mut name =
+        detector::which_case(s).to_case_lossy(language.preferred_case())?;
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    if language == Language::Sql {
+        name.truncate(SQL_MAX_IDENTIFIER_LENGTH);
+    }
+    if language.is_keyword(&name) {
+        name = language.escape(&name).into_owned();
+    }
+    Ok(Cow::Owned(name))
+}
@@ -0,0 +1,900 @@
+//! Hand-written lexers that find identifiers in source code,
+//! skipping strings, comments and keywords.
+//!
+//! Each identifier comes with its [Span] and a coarse [SymbolKind]
+//! if it is being declared there and that is cheap to tell,
+//! like the name after `fn` in Rust or after `def` in Python.
+//! Other identifiers, mostly references, have no kind.
+//!
+//! Rust, Python, Java, Kotlin, Go, JavaScript, TypeScript, C, C++, C#,
+//! Swift and SQL are supported. The lexers don't parse,
+//! so some declarations are missed, but an identifier is never
+//! taken from a string or a comment.
+//!
+//! # Examples
+//!
+//! ```
+//! use naming_lib::language::{Language, SymbolKind};
+//! use naming_lib::lexer::Lexer;
+//!
+//! let source = r#"
+//! // fn notThis() {}
+//! fn parse_args(argv: &[String]) -> Config {
+//!     let path = "fn notThisEither";
+//!     Config::load(path)
+//! }
+//! "#;
+//! let tokens: Vec<_> = Lexer::new(Language::Rust, source)
+//!     .map(|token| (token.text, token.kind))
+//!     .collect();
+//!
+//! assert_eq!(
+//!     vec![
+//!         ("parse_args", Some(SymbolKind::Function)),
+//!         ("argv", Some(SymbolKind::Parameter)),
+//!         ("String", None),
+//!         ("Config", None),
+//!         ("path", Some(SymbolKind::Variable)),
+//!         ("Config", None),
+//!         ("load", None),
+//!         ("path", None),
+//!     ],
+//!     tokens
+//! );
+//! ```
+
+use crate::language::{Language, SymbolKind};
+
+use rules::{BodyKind, Role};
+
+mod rules;
+mod scan;
+
+/// Where a token is in the source.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Span {
+    /// Byte offset of the first character.
+    pub start: usize,
+    /// Byte offset after the last character.
+    pub end: usize,
+    /// 1-based.
+    pub line: usize,
+    /// 1-based, counted in characters.
+    pub column: usize,
+}
+
+/// An identifier found by a [Lexer].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Token<'a> {
+    /// The identifier without escapes,
+    /// like `type` for the Rust raw identifier `r#type`.
+    pub text: &'a str,
+    pub span: Span,
+    /// The kind of symbol declared here,
+    /// [None] if it's a reference or can't be told.
+    pub kind: Option<SymbolKind>,
+}
+
+/// The last significant thing before the current position.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Prev {
+    Nothing,
+    Ident,
+    /// A built-in type like `int`.
+    Primitive,
+    Keyword,
+    Literal,
+    Punct(char),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Body {
+    kind: BodyKind,
+    /// Depth of braces inside the body.
+    braces: usize,
+    /// Depth of parentheses and brackets at the body.
+    parens: usize,
+}
+
+/// A list of parameters, or of columns in SQL.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Params {
+    /// Depth of parentheses inside the list.
+    parens: usize,
+    kind: SymbolKind,
+    /// The next identifier is a name, after `(` or `,`.
+    expect_name: bool,
+}
+
+/// Iterate over identifiers in source code, see the [module](self) docs.
+#[derive(Clone, Debug)]
+pub struct Lexer<'a> {
+    language: Language,
+    source: &'a str,
+    pos: usize,
+    line: usize,
+    line_start: usize,
+    /// Nothing but keywords is found on this line yet.
+    line_first: bool,
+    prev: Prev,
+    /// Declared by the last keyword, for the next identifier.
+    pending: Option<SymbolKind>,
+    /// Opened by the next `{`.
+    pending_body: Option<BodyKind>,
+    /// C and C++ types are only declared if a body or base follows,
+    /// otherwise `struct point p;` is a reference.
+    confirm_body: bool,
+    bodies: Vec<Body>,
+    braces: usize,
+    parens: usize,
+    /// `static`, `final` and `const` since the last statement.
+    is_static: bool,
+    is_final: bool,
+    is_const: bool,
+    /// The next `(` opens parameters of a declared function.
+    await_params: Option<SymbolKind>,
+    params: Option<Params>,
+    /// The next word is a C# verbatim identifier, like `@class`.
+    verbatim: bool,
+    /// Depth of parentheses inside a Go method receiver.
+    go_receiver: Option<usize>,
+    /// Kind and depth of parentheses inside a Go group like `const (`.
+    go_group: Option<(SymbolKind, usize)>,
+    /// In a SQL `CREATE` statement before the declared name.
+    sql_create: bool,
+}
+
+impl<'a> Lexer<'a> {
+    /// Create a lexer of the source code.
+    pub fn new(language: Language, source: &'a str) -> Lexer<'a> {
+        Lexer {
+            language,
+            source,
+            pos: 0,
+            line: 1,
+            line_start: 0,
+            line_first: true,
+            prev: Prev::Nothing,
+            pending: None,
+            pending_body: None,
+            confirm_body: false,
+            bodies: Vec::new(),
+            braces: 0,
+            parens: 0,
+            is_static: false,
+            is_final: false,
+            is_const: false,
+            await_params: None,
+            params: None,
+            verbatim: false,
+            go_receiver: None,
+            go_group: None,
+            sql_create: false,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    /// Move to a position, counting lines on the way.
+    fn advance_to(&mut self, pos: usize) {
+        let skipped = &self.source[self.pos..pos];
+        if let Some(i) = skipped.rfind('\n') {
+            self.line += skipped.matches('\n').count();
+            self.line_start = self.pos + i + 1;
+            self.line_first = true;
+        }
+        self.pos = pos;
+    }
+
+    fn skip_whitespace(&mut self) {
+        let end = self.source.len() - self.rest().trim_start().len();
+        self.advance_to(end);
+    }
+
+    fn column_of(&self, pos: usize) -> usize {
+        self.source[self.line_start..pos].chars().count() + 1
+    }
+
+    /// Get the first two characters after whitespaces from a position.
+    fn peek_after(&self, pos: usize) -> (Option<char>, Option<char>) {
+        let mut chars = self.source[pos..].trim_start().chars();
+        (chars.next(), chars.next())
+    }
+
+    fn dollar_in_identifiers(&self) -> bool {
+        matches!(
+            self.language,
+            Language::Java | Language::JavaScript | Language::TypeScript
+        )
+    }
+
+    /// Get the length of a comment at the current position.
+    fn comment_len(&self) -> Option<usize> {
+        let rest = self.rest();
+        let line_comment = match self.language {
+            Language::Python => "#",
+            Language::Sql => "--",
+            _ => "//",
+        };
+        if rest.starts_with(line_comment) {
+            return Some(scan::line_end(rest, 0));
+        }
+        if rest.starts_with("/*") && self.language != Language::Python {
+            let nested = matches!(
+                self.language,
+                Language::Rust | Language::Kotlin | Language::Swift
+            );
+            return Some(scan::block_comment_end(rest, 0, nested));
+        }
+        None
+    }
+
+    /// Get the length of a string or character literal
+    /// at the current position, given the word before it if any.
+    fn literal_len(&self, prefix: &str) -> Option<usize> {
+        let rest = &self.rest()[prefix.len()..];
+        let quoted = |quote: &str, escapes: bool, doubled: bool| {
+            Some(
+                prefix.len()
+                    + scan::quoted_end(rest, 0, quote, escapes, doubled),
+            )
+        };
+        let first = rest.chars().next()?;
+        let lang = self.language;
+
+        if !prefix.is_empty() {
+            let lower = prefix.to_ascii_lowercase();
+            let is_prefix = match lang {
+                Language::Python => {
+                    ["r", "u", "b", "f", "br", "rb", "fr", "rf"]
+                        .contains(&&*lower)
+                }
+                Language::Rust => ["b", "c"].contains(&prefix),
+                Language::C | Language::Cpp => {
+                    ["u8", "u", "U", "L"].contains(&prefix)
+                }
+                _ => false,
+            };
+            if !is_prefix || !(first == '"' || first == '\'') {
+                return None;
+            }
+        }
+
+        match (lang, first) {
+            (Language::Sql, '\'' | '"' | '`') => {
+                quoted(&first.to_string(), false, true)
+            }
+            (Language::Sql, _) => None,
+            (Language::Python, '"' | '\'') => {
+                let triple = first.to_string().repeat(3);
+                if rest.starts_with(&triple) {
+                    quoted(&triple, true, false)
+                } else {
+                    quoted(&first.to_string(), true, false)
+                }
+            }
+            (Language::Kotlin | Language::Swift | Language::Java, '"')
+                if rest.starts_with("\"\"\"") =>
+            {
+                quoted("\"\"\"", true, false)
+            }
+            (_, '"') => quoted("\"", true, false),
+            (Language::Rust, '\'') => {
+                let mut chars = rest.chars().skip(1);
+                match (chars.next(), chars.next()) {
+                    (Some('\\'), _) | (_, Some('\'')) => {
+                        quoted("'", true, false)
+                    }
+                    // A lifetime or a label, like `'a`.
+                    _ => match scan::ident_end(rest, 1, false) {
+                        1 => Some(prefix.len() + 1),
+                        end => Some(prefix.len() + end),
+                    },
+                }
+            }
+            (Language::Swift, '\'') => None,
+            (_, '\'') => quoted("'", true, false),
+            (Language::JavaScript | Language::TypeScript, '`') => {
+                quoted("`", true, false)
+            }
+            (Language::Go, '`') => quoted("`", false, false),
+            // Escaped identifiers, like `` `fun` ``.
+            (Language::Kotlin | Language::Swift, '`') => {
+                quoted("`", false, false)
+            }
+            (Language::CSharp, '@' | '$') => {
+                let marks = ["@$", "$@", "@", "$"].iter().find(|marks| {
+                    rest.strip_prefix(**marks)
+                        .is_some_and(|body| body.starts_with('"'))
+                })?;
+                let verbatim = marks.contains('@');
+                let body = &rest[marks.len()..];
+                Some(
+                    prefix.len()
+                        + marks.len()
+                        + scan::quoted_end(body, 0, "\"", !verbatim, verbatim),
+                )
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the length of a raw string starting with a word, like
+    /// `r#"..."#` in Rust or `R"(...)"` in C++, at the current position.
+    fn raw_string_len(&self, word: &str) -> Option<usize> {
+        let rest = self.rest();
+        match self.language {
+            Language::Rust if ["r", "br", "cr"].contains(&word) => {
+                let r = word.len() - 1;
+                scan::rust_raw_string_end(rest, r)
+            }
+            Language::Cpp
+                if ["R", "u8R", "uR", "UR", "LR"].contains(&word)
+                    && rest[word.len()..].starts_with('"') =>
+            {
+                Some(scan::cpp_raw_string_end(rest, word.len() - 1))
+            }
+            _ => None,
+        }
+    }
+
+    fn after_literal(&mut self) {
+        self.pending = None;
+        self.prev = Prev::Literal;
+        self.line_first = false;
+        if let Some(params) = &mut self.params {
+            params.expect_name = false;
+        }
+    }
+
+    fn innermost_body(&self) -> Option<&Body> {
+        self.bodies.last()
+    }
+
+    /// Get the innermost body if the current position is right in it,
+    /// not in a nested block or parentheses.
+    fn body_at_level(&self) -> Option<BodyKind> {
+        self.innermost_body()
+            .filter(|body| {
+                body.braces == self.braces && body.parens == self.parens
+            })
+            .map(|body| body.kind)
+    }
+
+    /// Handle a preprocessor directive, `#` is at the current position.
+    fn directive(&mut self) {
+        let start = self.pos + 1;
+        let rest = &self.source[start..];
+        let name_start =
+            start + (rest.len() - rest.trim_start_matches([' ', '\t']).len());
+        let name_end = scan::ident_end(self.source, name_start, false);
+        let name = &self.source[name_start..name_end];
+
+        match (self.language, name) {
+            (Language::C | Language::Cpp, "define") => {
+                self.advance_to(name_end);
+                self.pending = Some(SymbolKind::Constant);
+                self.prev = Prev::Keyword;
+            }
+            (
+                Language::C | Language::Cpp,
+                "include" | "pragma" | "error" | "warning" | "line",
+            )
+            | (Language::CSharp, _) => {
+                let end = scan::line_end(self.source, self.pos);
+                self.advance_to(end);
+            }
+            _ => self.advance_to(name_end),
+        }
+    }
+
+    fn apply_role(&mut self, role: Role) {
+        let prev = std::mem::replace(&mut self.prev, Prev::Keyword);
+        match role {
+            Role::Declares(kind) => {
+                // `const val` of Kotlin declares a constant.
+                if !(self.pending == Some(SymbolKind::Constant)
+                    && kind == SymbolKind::Variable)
+                {
+                    self.pending = Some(self.adjust_declared(kind));
+                }
+                // Not in parameters of a constructor, like `class A(val x: Int)`.
+                if self.parens == 0 {
+                    self.pending_body = None;
+                }
+            }
+            Role::DeclaresType(body) => {
+                // `enum class` of Kotlin declares an enum.
+                if self.pending_body != Some(BodyKind::Enum) {
+                    self.pending_body = Some(body);
+                }
+                self.pending = Some(SymbolKind::Type);
+                self.confirm_body =
+                    matches!(self.language, Language::C | Language::Cpp);
+            }
+            // Not in types like `-> impl Iterator`.
+            Role::Opens(body)
+                if matches!(
+                    prev,
+                    Prev::Nothing
+                        | Prev::Keyword
+                        | Prev::Ident
+                        | Prev::Punct('}' | ';' | '{' | ']')
+                ) =>
+            {
+                self.pending_body = Some(body)
+            }
+            Role::Opens(_) => {}
+            Role::Case => {
+                if self.body_at_level() == Some(BodyKind::Enum) {
+                    self.pending = Some(SymbolKind::EnumVariant);
+                }
+            }
+            Role::Primitive => self.prev = Prev::Primitive,
+            Role::Static => self.is_static = true,
+            Role::Final => self.is_final = true,
+            Role::Const => self.is_const = true,
+            Role::Modifier => {}
+        }
+    }
+
+    /// Functions in classes are methods, and so on.
+    fn adjust_declared(&self, kind: SymbolKind) -> SymbolKind {
+        let body = self.body_at_level();
+        match kind {
+            SymbolKind::Function if self.language == Language::Python => {
+                let line = &self.source[self.line_start..];
+                if line.starts_with([' ', '\t']) {
+                    SymbolKind::Method
+                } else {
+                    kind
+                }
+            }
+            SymbolKind::Function
+                if matches!(body, Some(BodyKind::Impl | BodyKind::Class)) =>
+            {
+                SymbolKind::Method
+            }
+            SymbolKind::Variable
+                if matches!(body, Some(BodyKind::Class | BodyKind::Struct)) =>
+            {
+                SymbolKind::Field
+            }
+            _ => kind,
+        }
+    }
+
+    /// Handle a keyword or a SQL word, return true if it isn't an identifier.
+    fn keyword(&mut self, word: &str, word_end: usize) -> bool {
+        if self.language == Language::Sql {
+            if self.sql_create {
+                if let Some(kind) = rules::sql_create_word(word) {
+                    if kind.is_some() {
+                        self.pending = kind;
+                    }
+                    self.prev = Prev::Keyword;
+                    return true;
+                }
+                self.sql_create = false;
+            }
+            if word.eq_ignore_ascii_case("CREATE") {
+                self.sql_create = true;
+                self.pending = None;
+            }
+        }
+
+        let reserved = self.language.is_keyword(word);
+        let role = rules::role_of(self.language, word);
+        // Contextual keywords are only keywords before another word.
+        let before_word = self.peek_after(word_end).0.is_some_and(|c| {
+            scan::is_ident_start(c, self.dollar_in_identifiers())
+        });
+
+        match role {
+            Some(role) if reserved || before_word => self.apply_role(role),
+            None if reserved => self.prev = Prev::Keyword,
+            _ => return false,
+        }
+        if !(self.language == Language::Rust && ["mut", "ref"].contains(&word))
+        {
+            if let Some(params) = &mut self.params {
+                params.expect_name = false;
+            }
+        }
+        true
+    }
+
+    /// Tell the kind of an identifier, by the state and what follows it.
+    fn classify(&mut self, end: usize) -> Option<SymbolKind> {
+        use SymbolKind::*;
+
+        let (next, next2) = self.peek_after(end);
+        let qualified = next == Some('.') && next2 != Some('.');
+        let lang = self.language;
+
+        if let Some(kind) = self.pending.take() {
+            // Receivers of Kotlin extensions and SQL schemas,
+            // like `String.trim` or `public.users`.
+            if qualified && matches!(lang, Language::Kotlin | Language::Sql) {
+                self.pending = Some(kind);
+                return None;
+            }
+            if std::mem::take(&mut self.confirm_body)
+                && !matches!(next, Some('{' | ':'))
+            {
+                self.pending_body = None;
+                return None;
+            }
+            // Patterns like `let Some(x)` or `let Point { x, y }`.
+            if lang == Language::Rust
+                && kind == Variable
+                && (matches!(next, Some('(' | '{'))
+                    || (next == Some(':') && next2 == Some(':')))
+            {
+                return None;
+            }
+            // Anonymous functions like `func(x int)` aren't receivers.
+            if lang == Language::Go && kind == Method && next != Some('(') {
+                return None;
+            }
+            match kind {
+                Function | Method => self.await_params = Some(Parameter),
+                Type if lang == Language::Sql => {
+                    self.await_params = Some(Field)
+                }
+                _ => {}
+            }
+            return Some(kind);
+        }
+
+        if self.go_receiver.is_some() {
+            return None;
+        }
+        if let Some((kind, parens)) = self.go_group {
+            if self.parens == parens && self.line_first {
+                return Some(kind);
+            }
+        }
+        if let Some(params) = &mut self.params {
+            if params.parens == self.parens && params.expect_name {
+                params.expect_name = false;
+                // Not in types like `impl Fn() -> Result<(), E>`.
+                let typed = next == Some(':') && next2 != Some(':');
+                if lang != Language::Rust || typed {
+                    return Some(params.kind);
+                }
+            }
+        }
+
+        if let Some(body) = self.body_at_level() {
+            let after_separator =
+                matches!(self.prev, Prev::Punct('{' | ',' | ']'));
+            match body {
+                BodyKind::Enum if after_separator && !qualified => {
+                    return Some(EnumVariant)
+                }
+                BodyKind::Struct
+                    if lang == Language::Rust
+                        && next == Some(':')
+                        && next2 != Some(':') =>
+                {
+                    return Some(Field)
+                }
+                BodyKind::Struct if lang == Language::Go && self.line_first => {
+                    return if qualified { None } else { Some(Field) }
+                }
+                BodyKind::Class if lang == Language::Go && self.line_first => {
+                    if next != Some('(') {
+                        return None;
+                    }
+                    self.await_params = Some(Parameter);
+                    return Some(Method);
+                }
+                BodyKind::Class
+                    if matches!(
+                        lang,
+                        Language::JavaScript | Language::TypeScript
+                    ) && self.line_first =>
+                {
+                    return match next {
+                        Some('(') => {
+                            self.await_params = Some(Parameter);
+                            Some(Method)
+                        }
+                        Some('=' | ';' | ':' | '?' | '!') => Some(Field),
+                        _ => None,
+                    };
+                }
+                _ => {}
+            }
+        }
+
+        let c_like = matches!(
+            lang,
+            Language::Java | Language::CSharp | Language::C | Language::Cpp
+        );
+        let after_type = matches!(
+            self.prev,
+            Prev::Ident | Prev::Primitive | Prev::Punct('>' | ']')
+        );
+        if c_like && after_type {
+            let body = self.body_at_level();
+            let base_parens =
+                self.innermost_body().map_or(0, |body| body.parens);
+            return match (next, next2) {
+                (Some('('), _) => {
+                    if matches!(lang, Language::Java | Language::CSharp)
+                        || matches!(body, Some(BodyKind::Class))
+                    {
+                        Some(Method)
+                    } else {
+                        Some(Function)
+                    }
+                }
+                (Some('='), next2) if next2 != Some('=') => {
+                    Some(self.variable_kind(body, base_parens))
+                }
+                (Some(';' | ',' | ')' | '['), _) => {
+                    Some(self.variable_kind(body, base_parens))
+                }
+                _ => None,
+            };
+        }
+
+        if lang == Language::Go && next == Some(':') && next2 == Some('=') {
+            return Some(Variable);
+        }
+        None
+    }
+
+    fn variable_kind(
+        &self,
+        body: Option<BodyKind>,
+        base_parens: usize,
+    ) -> SymbolKind {
+        if self.parens > base_parens {
+            SymbolKind::Parameter
+        } else if (self.is_static && self.is_final) || self.is_const {
+            SymbolKind::Constant
+        } else if matches!(body, Some(BodyKind::Class | BodyKind::Struct)) {
+            SymbolKind::Field
+        } else {
+            SymbolKind::Variable
+        }
+    }
+
+    fn end_statement(&mut self) {
+        self.is_static = false;
+        self.is_final = false;
+        self.is_const = false;
+    }
+
+    fn punct(&mut self, c: char) {
+        // Generators like `function* walk`, and qualified names
+        // like `fun String.trim` of Kotlin or `public.users` of SQL.
+        let keep_pending = match c {
+            '*' => matches!(
+                self.language,
+                Language::JavaScript | Language::TypeScript
+            ),
+            '.' => matches!(self.language, Language::Kotlin | Language::Sql),
+            _ => false,
+        };
+        match c {
+            '{' => {
+                self.braces += 1;
+                if let Some(kind) = self.pending_body.take() {
+                    self.bodies.push(Body {
+                        kind,
+                        braces: self.braces,
+                        parens: self.parens,
+                    });
+                }
+                self.await_params = None;
+                self.end_statement();
+            }
+            '}' => {
+                if self
+                    .innermost_body()
+                    .is_some_and(|body| body.braces == self.braces)
+                {
+                    self.bodies.pop();
+                }
+                self.braces = self.braces.saturating_sub(1);
+                self.end_statement();
+            }
+            '(' | '[' => {
+                if c == '('
+                    && self.language == Language::Go
+                    && self.prev == Prev::Keyword
+                {
+                    match self.pending {
+                        Some(SymbolKind::Function) => {
+                            self.go_receiver = Some(self.parens + 1);
+                        }
+                        Some(
+                            kind @ (SymbolKind::Constant
+                            | SymbolKind::Variable
+                            | SymbolKind::Type),
+                        ) => {
+                            self.go_group = Some((kind, self.parens + 1));
+                        }
+                        _ => {}
+                    }
+                }
+                self.parens += 1;
+                if c == '(' {
+                    if let Some(kind) = self.await_params.take() {
+                        self.params = Some(Params {
+                            parens: self.parens,
+                            kind,
+                            expect_name: true,
+                        });
+                    }
+                }
+            }
+            ')' | ']' => {
+                if self
+                    .params
+                    .is_some_and(|params| params.parens == self.parens)
+                {
+                    self.params = None;
+                }
+                if self.go_receiver == Some(self.parens) {
+                    self.go_receiver = None;
+                    self.parens -= 1;
+                    self.pending = Some(SymbolKind::Method);
+                    self.prev = Prev::Punct(c);
+                    self.line_first = false;
+                    return;
+                }
+                if self
+                    .go_group
+                    .is_some_and(|(_, parens)| parens == self.parens)
+                {
+                    self.go_group = None;
+                }
+                self.parens = self.parens.saturating_sub(1);
+            }
+            ',' => {
+                if let Some(params) = &mut self.params {
+                    if params.parens == self.parens {
+                        params.expect_name = true;
+                    }
+                }
+            }
+            ';' => {
+                // Members follow constants of Java and C# enums.
+                if self.body_at_level() == Some(BodyKind::Enum) {
+                    if let Some(body) = self.bodies.last_mut() {
+                        body.kind = BodyKind::Class;
+                    }
+                }
+                self.pending_body = None;
+                self.await_params = None;
+                self.end_statement();
+            }
+            '@' if self.language == Language::CSharp => {
+                self.verbatim = true;
+                return;
+            }
+            _ => {}
+        }
+
+        if !keep_pending {
+            self.pending = None;
+        }
+        // `>` closes generics like `List<String> names`,
+        // but not comparisons like `a > b`.
+        let spaced = self.source[..self.pos].ends_with(char::is_whitespace);
+        self.prev = match c {
+            '>' if spaced => Prev::Punct(' '),
+            _ => Prev::Punct(c),
+        };
+        self.line_first = false;
+    }
+
+    /// Handle a word at the current position,
+    /// return a token if it is an identifier.
+    fn word(&mut self) -> Option<Token<'a>> {
+        let dollar = self.dollar_in_identifiers();
+        let start = self.pos;
+        let end = scan::ident_end(self.source, start, dollar);
+        let word = &self.source[start..end];
+
+        if let Some(len) =
+            self.raw_string_len(word).or_else(|| self.literal_len(word))
+        {
+            self.advance_to(start + len);
+            self.after_literal();
+            return None;
+        }
+
+        // Rust raw identifiers, like `r#type`.
+        let (start, end, escaped) = if self.language == Language::Rust
+            && word == "r"
+            && self.source[end..].starts_with('#')
+            && self.source[end + 1..]
+                .chars()
+                .next()
+                .is_some_and(|c| scan::is_ident_start(c, false))
+        {
+            let raw_start = end + 1;
+            (raw_start, scan::ident_end(self.source, raw_start, false), true)
+        } else {
+            (start, end, std::mem::take(&mut self.verbatim))
+        };
+        let word = &self.source[start..end];
+        self.advance_to(end);
+
+        if !escaped && self.keyword(word, end) {
+            return None;
+        }
+
+        let kind = self.classify(end);
+        self.prev = Prev::Ident;
+        let token = Token {
+            text: word,
+            span: Span {
+                start,
+                end,
+                line: self.line,
+                column: self.column_of(start),
+            },
+            kind,
+        };
+        self.line_first = false;
+        Some(token)
+    }
+}
+
+impl<'a> Iterator for Lexer<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        loop {
+            self.skip_whitespace();
+            let c = self.rest().chars().next()?;
+
+            if let Some(len) = self.comment_len() {
+                self.advance_to(self.pos + len);
+                continue;
+            }
+            if let Some(len) = self.literal_len("") {
+                self.advance_to(self.pos + len);
+                self.after_literal();
+                continue;
+            }
+            if c.is_ascii_digit() {
+                let end = scan::number_end(self.source, self.pos);
+                self.advance_to(end);
+                self.after_literal();
+                continue;
+            }
+            if c == '#'
+                && self.line_first
+                && matches!(
+                    self.language,
+                    Language::C
+                        | Language::Cpp
+                        | Language::CSharp
+                        | Language::Swift
+                )
+            {
+                self.directive();
+                continue;
+            }
+            if scan::is_ident_start(c, self.dollar_in_identifiers()) {
+                match self.word() {
+                    Some(token) => return Some(token),
+                    None => continue,
+                }
+            }
+
+            self.punct(c);
+            self.advance_to(self.pos + c.len_utf8());
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::language::{Language, SymbolKind};
+
+/// Kinds of bodies between braces that hold declarations.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(super) enum BodyKind {
+    /// Fields, like Rust and C structs or Go structs.
+    Struct,
+    /// Variants.
+    Enum,
+    /// Fields and methods, like classes and interfaces.
+    Class,
+    /// Methods only, like Rust impl blocks and traits.
+    Impl,
+}
+
+/// What a keyword tells about the next identifier.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(super) enum Role {
+    /// Declares the next identifier.
+    Declares(SymbolKind),
+    /// Declares the next identifier as a type with a body.
+    DeclaresType(BodyKind),
+    /// Opens a body without declaring a name, like `impl` in Rust.
+    Opens(BodyKind),
+    /// Declares enum variants in an enum body, like `case` in Swift.
+    Case,
+    /// A built-in type, which may be followed by a declared name.
+    Primitive,
+    /// `static` and `final` of Java.
+    Static,
+    Final,
+    /// `const` of C#.
+    Const,
+    /// A contextual keyword that only modifies a declaration,
+    /// like `data` in `data class` of Kotlin.
+    Modifier,
+}
+
+use BodyKind::*;
+use Role::*;
+use SymbolKind::{
+    Constant, Function, Module, Static as StaticKind, Type, Variable,
+};
+
+#[rustfmt::skip]
+const RUST: &[(&str, Role)] = &[
+    ("fn", Declares(Function)), ("struct", DeclaresType(Struct)),
+    ("enum", DeclaresType(Enum)), ("union", DeclaresType(Struct)),
+    ("trait", DeclaresType(Impl)), ("impl", Opens(Impl)),
+    ("type", Declares(Type)), ("const", Declares(Constant)),
+    ("static", Declares(StaticKind)), ("mod", Declares(Module)),
+    ("let", Declares(Variable)),
+];
+
+#[rustfmt::skip]
+const PYTHON: &[(&str, Role)] = &[
+    ("def", Declares(Function)), ("class", Declares(Type)),
+];
+
+#[rustfmt::skip]
+const JAVA: &[(&str, Role)] = &[
+    ("class", DeclaresType(Class)), ("interface", DeclaresType(Class)),
+    ("enum", DeclaresType(Enum)), ("record", DeclaresType(Class)),
+    ("static", Static), ("final", Final),
+    ("void", Primitive), ("boolean", Primitive), ("byte", Primitive),
+    ("char", Primitive), ("short", Primitive), ("int", Primitive),
+    ("long", Primitive), ("float", Primitive), ("double", Primitive),
+    ("var", Primitive), ("sealed", Modifier), ("permits", Modifier),
+    ("yield", Modifier),
+];
+
+#[rustfmt::skip]
+const KOTLIN: &[(&str, Role)] = &[
+    ("fun", Declares(Function)), ("class", DeclaresType(Class)),
+    ("interface", DeclaresType(Class)), ("object", DeclaresType(Class)),
+    ("enum", DeclaresType(Enum)), ("typealias", Declares(Type)),
+    ("val", Declares(Variable)), ("var", Declares(Variable)),
+    ("const", Declares(Constant)), ("data", Modifier), ("sealed", Modifier),
+    ("open", Modifier), ("abstract", Modifier), ("override", Modifier),
+    ("private", Modifier), ("public", Modifier), ("internal", Modifier),
+    ("protected", Modifier), ("lateinit", Modifier), ("suspend", Modifier),
+    ("inline", Modifier), ("inner", Modifier), ("companion", Modifier),
+    ("annotation", Modifier), ("value", Modifier),
+];
+
+#[rustfmt::skip]
+const GO: &[(&str, Role)] = &[
+    ("func", Declares(Function)), ("type", Declares(Type)),
+    ("var", Declares(Variable)), ("const", Declares(Constant)),
+    ("package", Declares(Module)), ("struct", Opens(Struct)),
+    ("interface", Opens(Class)),
+];
+
+#[rustfmt::skip]
+const JAVASCRIPT: &[(&str, Role)] = &[
+    ("function", Declares(Function)), ("class", DeclaresType(Class)),
+    ("let", Declares(Variable)), ("var", Declares(Variable)),
+    ("const", Declares(Variable)), ("async", Modifier), ("get", Modifier),
+    ("set", Modifier), ("static", Modifier),
+];
+
+#[rustfmt::skip]
+const TYPESCRIPT: &[(&str, Role)] = &[
+    ("function", Declares(Function)), ("class", DeclaresType(Class)),
+    ("interface", DeclaresType(Class)), ("enum", DeclaresType(Enum)),
+    ("type", Declares(Type)), ("namespace", Declares(Module)),
+    ("let", Declares(Variable)), ("var", Declares(Variable)),
+    ("const", Declares(Variable)), ("async", Modifier), ("get", Modifier),
+    ("set", Modifier), ("static", Modifier), ("readonly", Modifier),
+    ("abstract", Modifier), ("declare", Modifier), ("override", Modifier),
+    ("private", Modifier), ("protected", Modifier), ("public", Modifier),
+];
+
+#[rustfmt::skip]
+const C: &[(&str, Role)] = &[
+    ("struct", DeclaresType(Struct)), ("union", DeclaresType(Struct)),
+    ("enum", DeclaresType(Enum)), ("void", Primitive), ("char", Primitive),
+    ("short", Primitive), ("int", Primitive), ("long", Primitive),
+    ("float", Primitive), ("double", Primitive), ("signed", Primitive),
+    ("unsigned", Primitive), ("bool", Primitive), ("_Bool", Primitive),
+];
+
+#[rustfmt::skip]
+const CPP: &[(&str, Role)] = &[
+    ("struct", DeclaresType(Class)), ("union", DeclaresType(Struct)),
+    ("class", DeclaresType(Class)), ("enum", DeclaresType(Enum)),
+    ("namespace", Declares(Module)), ("void", Primitive), ("char", Primitive),
+    ("short", Primitive), ("int", Primitive), ("long", Primitive),
+    ("float", Primitive), ("double", Primitive), ("signed", Primitive),
+    ("unsigned", Primitive), ("bool", Primitive), ("auto", Primitive),
+    ("wchar_t", Primitive), ("char8_t", Primitive), ("char16_t", Primitive),
+    ("char32_t", Primitive),
+];
+
+#[rustfmt::skip]
+const CSHARP: &[(&str, Role)] = &[
+    ("class", DeclaresType(Class)), ("interface", DeclaresType(Class)),
+    ("struct", DeclaresType(Class)), ("record", DeclaresType(Class)),
+    ("enum", DeclaresType(Enum)), ("namespace", Declares(Module)),
+    ("const", Const), ("void", Primitive), ("bool", Primitive),
+    ("byte", Primitive), ("sbyte", Primitive), ("char", Primitive),
+    ("short", Primitive), ("ushort", Primitive), ("int", Primitive),
+    ("uint", Primitive), ("long", Primitive), ("ulong", Primitive),
+    ("float", Primitive), ("double", Primitive), ("decimal", Primitive),
+    ("string", Primitive), ("object", Primitive), ("dynamic", Primitive),
+    ("var", Primitive), ("async", Modifier), ("await", Modifier),
+    ("partial", Modifier), ("yield", Modifier),
+];
+
+#[rustfmt::skip]
+const SWIFT: &[(&str, Role)] = &[
+    ("func", Declares(Function)), ("class", DeclaresType(Class)),
+    ("struct", DeclaresType(Class)), ("protocol", DeclaresType(Class)),
+    ("extension", Opens(Class)), ("enum", DeclaresType(Enum)),
+    ("typealias", Declares(Type)), ("let", Declares(Variable)),
+    ("var", Declares(Variable)), ("case", Case), ("mutating", Modifier),
+    ("override", Modifier), ("final", Modifier), ("lazy", Modifier),
+    ("weak", Modifier), ("indirect", Modifier), ("convenience", Modifier),
+];
+
+/// Get the role of a word, reserved or contextual.
+pub(super) fn role_of(language: Language, word: &str) -> Option<Role> {
+    let table = match language {
+        Language::Rust => RUST,
+        Language::Python => PYTHON,
+        Language::Java => JAVA,
+        Language::Kotlin => KOTLIN,
+        Language::Go => GO,
+        Language::JavaScript => JAVASCRIPT,
+        Language::TypeScript => TYPESCRIPT,
+        Language::C => C,
+        Language::Cpp => CPP,
+        Language::CSharp => CSHARP,
+        Language::Swift => SWIFT,
+        Language::Sql => return None,
+    };
+    table.iter().find(|(w, _)| *w == word).map(|(_, role)| *role)
+}
+
+/// Words between `CREATE` and the declared name in SQL,
+/// with the kind they declare.
+#[rustfmt::skip]
+const SQL_CREATE: &[(&str, Option<SymbolKind>)] = &[
+    ("OR", None), ("REPLACE", None), ("TEMP", None), ("TEMPORARY", None),
+    ("UNIQUE", None), ("MATERIALIZED", None), ("IF", None), ("NOT", None),
+    ("EXISTS", None), ("TABLE", Some(Type)), ("VIEW", Some(Type)),
+    ("TYPE", Some(Type)), ("INDEX", None), ("TRIGGER", None),
+    ("SEQUENCE", None), ("FUNCTION", Some(Function)),
+    ("PROCEDURE", Some(Function)), ("SCHEMA", Some(Module)),
+];
+
+/// Get the kind declared by a word after `CREATE`,
+/// [None] if the word doesn't belong to the statement.
+pub(super) fn sql_create_word(word: &str) -> Option<Option<SymbolKind>> {
+    SQL_CREATE
+        .iter()
+        .find(|(w, _)| w.eq_ignore_ascii_case(word))
+        .map(|(_, kind)| *kind)
+}
@@ -0,0 +1,115 @@
+// Scanners of comments, literals and words.
+// Each one takes the source and the position where the thing starts,
+// and returns the position right after it, or the end of the source.
+
+use unicode_xid::UnicodeXID;
+
+pub(super) fn is_ident_start(c: char, dollar: bool) -> bool {
+    c.is_xid_start() || c == '_' || (dollar && c == '$')
+}
+
+pub(super) fn ident_end(s: &str, pos: usize, dollar: bool) -> usize {
+    s[pos..]
+        .char_indices()
+        .find(|&(_, c)| !(c.is_xid_continue() || (dollar && c == '$')))
+        .map_or(s.len(), |(i, _)| pos + i)
+}
+
+pub(super) fn line_end(s: &str, pos: usize) -> usize {
+    s[pos..].find('\n').map_or(s.len(), |i| pos + i)
+}
+
+/// `pos` is at `/*`.
+pub(super) fn block_comment_end(s: &str, pos: usize, nested: bool) -> usize {
+    let bytes = s.as_bytes();
+    let mut depth = 0;
+    let mut i = pos;
+    while i + 1 < bytes.len() {
+        match (bytes[i], bytes[i + 1]) {
+            (b'/', b'*') if nested || depth == 0 => {
+                depth += 1;
+                i += 2;
+            }
+            (b'*', b'/') => {
+                depth -= 1;
+                i += 2;
+                if depth == 0 {
+                    return i;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    s.len()
+}
+
+/// `pos` is at the opening quote, which may be several characters
+/// like `"""`. Backslashes escape the next character if `escapes`,
+/// and a doubled quote stands for itself if `doubled`.
+pub(super) fn quoted_end(
+    s: &str,
+    pos: usize,
+    quote: &str,
+    escapes: bool,
+    doubled: bool,
+) -> usize {
+    let mut i = pos + quote.len();
+    while i < s.len() {
+        let rest = &s[i..];
+        if escapes && rest.starts_with('\\') {
+            i += 1 + rest[1..].chars().next().map_or(0, char::len_utf8);
+        } else if let Some(after) = rest.strip_prefix(quote) {
+            if doubled && after.starts_with(quote) {
+                i += quote.len() * 2;
+            } else {
+                return i + quote.len();
+            }
+        } else {
+            i += rest.chars().next().unwrap().len_utf8();
+        }
+    }
+    s.len()
+}
+
+/// `pos` is at `r` of Rust raw strings like `r#"..."#`,
+/// return [None] if it isn't one.
+pub(super) fn rust_raw_string_end(s: &str, pos: usize) -> Option<usize> {
+    let rest = s[pos..].strip_prefix('r')?;
+    let hashes = rest.len() - rest.trim_start_matches('#').len();
+    if !rest[hashes..].starts_with('"') {
+        return None;
+    }
+    let closing = format!("\"{}", "#".repeat(hashes));
+    let body = pos + 1 + hashes + 1;
+    Some(s[body..].find(&closing).map_or(s.len(), |i| body + i + closing.len()))
+}
+
+/// `pos` is at `R"` of C++ raw strings like `R"delim(...)delim"`.
+pub(super) fn cpp_raw_string_end(s: &str, pos: usize) -> usize {
+    let open = pos + 2;
+    let delimiter = match s[open..].find('(') {
+        Some(i) => &s[open..open + i],
+        None => return s.len(),
+    };
+    let closing = format!("){}\"", delimiter);
+    let body = open + delimiter.len() + 1;
+    s[body..].find(&closing).map_or(s.len(), |i| body + i + closing.len())
+}
+
+/// Digits, letters of suffixes and bases like `0x1Fu32`,
+/// and a dot only if a digit follows, so `x.0.y` keeps `y`.
+pub(super) fn number_end(s: &str, pos: usize) -> usize {
+    let bytes = s.as_bytes();
+    let mut i = pos;
+    while i < bytes.len() {
+        let b = bytes[i];
+        let fraction = b == b'.'
+            && bytes.get(i + 1).is_some_and(|next| next.is_ascii_digit());
+        if b.is_ascii_alphanumeric() || b == b'_' || fraction {
+            i += 1;
+        } else {
+            break;
+        }
+    }
+    i
+}
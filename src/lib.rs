@@ -14,7 +14,26 @@
 // We'll test them in integrate tests.
 
 pub use detector::*;
+pub use ext::*;
+pub use insensitive::*;
 pub use naming_case::*;
+pub use validated::*;
 
+pub mod allocator;
+pub mod batch;
+pub mod compat;
 mod detector;
+mod ext;
+pub mod hungarian;
+mod insensitive;
+mod json;
+pub mod language;
+pub mod lexer;
+pub mod lint;
 mod naming_case;
+#[cfg(any(feature = "serde_json", feature = "toml"))]
+pub mod rekey;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod type_prefix;
+mod validated;
@@ -0,0 +1,280 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use toml::value::{Table, Value};
+
+use super::{AcronymPolicy, Rules};
+use crate::language::{Convention, Language, SymbolKind};
+use crate::TargetCase;
+
+/// Name of config files, looked up in every linted directory
+/// and in directories containing the linted path.
+pub const CONFIG_FILE_NAME: &str = ".naming.toml";
+
+/// Settings of a config file.
+///
+/// Rules are inherited from config files of parent directories,
+/// and every setting given here overrides or extends them
+/// in this directory and its subdirectories.
+///
+/// ```toml
+/// # Don't inherit rules from parent directories.
+/// root = true
+/// # Or "capitalize" to reject `HTTPServer`.
+/// acronyms = "preserve"
+/// exceptions = ["iOS", "macOS"]
+/// ignore = ["target", "*.pb.go", "src/generated/**"]
+///
+/// [rust]
+/// function = "snake"
+/// # Don't check fields.
+/// field = "none"
+///
+/// [go]
+/// function = "go-visibility"
+/// ```
+///
+/// Tables are named after [languages](Language::name()),
+/// and keys in them after [kinds of symbols](SymbolKind::name()).
+/// Conventions are [target case names](TargetCase::name()),
+/// `go-visibility` or `none`.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Config {
+    /// Don't inherit rules from parent directories.
+    pub root: bool,
+    pub acronyms: Option<AcronymPolicy>,
+    /// Added to inherited exceptions.
+    pub exceptions: Vec<String>,
+    /// Added to inherited ignore patterns, relative to the directory
+    /// of the config file, see [add_ignore()](Rules::add_ignore()).
+    pub ignore: Vec<String>,
+    /// Override conventions of kinds of symbols, [None] to not check.
+    pub conventions: Vec<(Language, SymbolKind, Option<Convention>)>,
+}
+
+/// A config file that can't be read or has a bad setting.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ConfigError {
+    /// The config file, [None] if it isn't read from a file.
+    pub path: Option<PathBuf>,
+    /// Dotted path of the bad key, like `rust.function` or `exceptions[1]`,
+    /// [None] if the file can't be read or parsed.
+    pub key: Option<String>,
+    pub message: String,
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(path) = &self.path {
+            write!(f, "{}: ", path.display())?;
+        }
+        if let Some(key) = &self.key {
+            write!(f, "`{}`: ", key)?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ConfigError {}
+
+impl Config {
+    /// Parse the content of a config file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use naming_lib::language::{Convention, Language, SymbolKind};
+    /// use naming_lib::lint::{AcronymPolicy, Config};
+    /// use naming_lib::TargetCase;
+    ///
+    /// let config = Config::parse("acronyms = \"capitalize\"\n[rust]\nfield = \"camel\"").unwrap();
+    /// assert_eq!(Some(AcronymPolicy::Capitalize), config.acronyms);
+    /// assert_eq!(
+    ///     vec![(Language::Rust, SymbolKind::Field, Some(Convention::Case(TargetCase::Camel)))],
+    ///     config.conventions
+    /// );
+    ///
+    /// let error = Config::parse("[rust]\nfunction = \"snek\"").unwrap_err();
+    /// assert_eq!("`rust.function`: Unknown convention name.", error.to_string());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Get a [ConfigError] if the content isn't valid TOML,
+    /// or has an unknown key or a bad value.
+    pub fn parse(content: &str) -> Result<Config, ConfigError> {
+        let table: Table = toml::from_str(content).map_err(|e| {
+            ConfigError { path: None, key: None, message: e.to_string() }
+        })?;
+
+        let mut config = Config::default();
+        for (key, value) in &table {
+            let at = |message: &str| ConfigError {
+                path: None,
+                key: Some(key_path(&[key])),
+                message: message.to_string(),
+            };
+            match key.as_str() {
+                "root" => {
+                    config.root = value
+                        .as_bool()
+                        .ok_or_else(|| at("Expected a boolean."))?
+                }
+                "acronyms" => {
+                    let name = value
+                        .as_str()
+                        .ok_or_else(|| at("Expected a string."))?;
+                    config.acronyms = Some(name.parse().map_err(at)?);
+                }
+                "exceptions" => {
+                    config.exceptions = strings(key, value)?;
+                    if config.exceptions.iter().any(String::is_empty) {
+                        return Err(at("Empty exception."));
+                    }
+                }
+                "ignore" => {
+                    config.ignore = strings(key, value)?;
+                    for (i, pattern) in config.ignore.iter().enumerate() {
+                        // Patterns are only checked here.
+                        Rules::default()
+                            .add_ignore(Path::new(""), pattern)
+                            .map_err(|message| ignore_error(i, message))?;
+                    }
+                }
+                _ => {
+                    let language: Language =
+                        key.parse().map_err(|_| at("Unknown key."))?;
+                    let kinds = value
+                        .as_table()
+                        .ok_or_else(|| at("Expected a table."))?;
+                    for (kind_key, value) in kinds {
+                        let at = |message: &str| ConfigError {
+                            path: None,
+                            key: Some(key_path(&[key, kind_key])),
+                            message: message.to_string(),
+                        };
+                        let kind: SymbolKind = kind_key.parse().map_err(at)?;
+                        let name = value
+                            .as_str()
+                            .ok_or_else(|| at("Expected a string."))?;
+                        let convention = parse_convention(name).map_err(at)?;
+                        config.conventions.push((language, kind, convention));
+                    }
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    /// Read and parse a config file.
+    ///
+    /// # Errors
+    ///
+    /// Get a [ConfigError] with the path if the file can't be read
+    /// or can't be [parsed](Config::parse()).
+    pub fn read(path: &Path) -> Result<Config, ConfigError> {
+        let with_path = |e: ConfigError| ConfigError {
+            path: Some(path.to_path_buf()),
+            ..e
+        };
+        let content = fs::read_to_string(path).map_err(|e| {
+            with_path(ConfigError {
+                path: None,
+                key: None,
+                message: e.to_string(),
+            })
+        })?;
+        Config::parse(&content).map_err(with_path)
+    }
+
+    /// Apply the settings on inherited rules,
+    /// for the directory where the config file is.
+    ///
+    /// # Errors
+    ///
+    /// Get a [ConfigError] if an ignore pattern is bad,
+    /// the rules are left unchanged in this case.
+    pub fn apply(
+        &self,
+        rules: &mut Rules,
+        directory: &Path,
+    ) -> Result<(), ConfigError> {
+        let mut applied =
+            if self.root { Rules::standard() } else { rules.clone() };
+        if let Some(acronyms) = self.acronyms {
+            applied.acronyms = acronyms;
+        }
+        applied.exceptions.extend(self.exceptions.iter().cloned());
+        for (i, pattern) in self.ignore.iter().enumerate() {
+            applied
+                .add_ignore(directory, pattern)
+                .map_err(|message| ignore_error(i, message))?;
+        }
+        for (language, kind, convention) in &self.conventions {
+            applied.preset.set(*language, *kind, *convention);
+        }
+        *rules = applied;
+        Ok(())
+    }
+}
+
+fn ignore_error(i: usize, message: &str) -> ConfigError {
+    ConfigError {
+        path: None,
+        key: Some(format!("ignore[{}]", i)),
+        message: message.to_string(),
+    }
+}
+
+fn strings(key: &str, value: &Value) -> Result<Vec<String>, ConfigError> {
+    let at = |key: String, message: &str| ConfigError {
+        path: None,
+        key: Some(key),
+        message: message.to_string(),
+    };
+    let values = value
+        .as_array()
+        .ok_or_else(|| at(key_path(&[key]), "Expected an array of strings."))?;
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| match value.as_str() {
+            Some(s) => Ok(s.to_string()),
+            None => Err(at(
+                format!("{}[{}]", key_path(&[key]), i),
+                "Expected a string.",
+            )),
+        })
+        .collect()
+}
+
+fn parse_convention(name: &str) -> Result<Option<Convention>, &'static str> {
+    match name {
+        "none" => Ok(None),
+        "go-visibility" => Ok(Some(Convention::GoVisibility)),
+        _ => name
+            .parse::<TargetCase>()
+            .map(|case| Some(Convention::Case(case)))
+            .map_err(|_| "Unknown convention name."),
+    }
+}
+
+/// Join keys with dots, quoting those that aren't bare keys like `"c++"`.
+fn key_path(keys: &[&str]) -> String {
+    keys.iter()
+        .map(|key| {
+            let bare = !key.is_empty()
+                && key
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+            if bare {
+                key.to_string()
+            } else {
+                format!("{:?}", key)
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(".")
+}
@@ -0,0 +1,418 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use super::{language_of, Diagnostic, Linter};
+use crate::language::Language;
+use crate::lexer::{Lexer, Span};
+
+/// Lines of context around changes in diffs.
+const CONTEXT: usize = 3;
+
+/// Where references of a renamed identifier are renamed too.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Scope {
+    /// In the file declaring it.
+    #[default]
+    File,
+    /// In every source added to the [Fixer].
+    Project,
+}
+
+impl Scope {
+    /// Get the name, "file" or "project".
+    pub fn name(self) -> &'static str {
+        match self {
+            Scope::File => "file",
+            Scope::Project => "project",
+        }
+    }
+}
+
+impl FromStr for Scope {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "file" => Ok(Scope::File),
+            "project" => Ok(Scope::Project),
+            _ => Err("Unknown scope name."),
+        }
+    }
+}
+
+/// A violation that isn't fixed, and why.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Refusal {
+    pub diagnostic: Diagnostic,
+    pub reason: &'static str,
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let diagnostic = &self.diagnostic;
+        write!(
+            f,
+            "{}: can't rename `{}`",
+            diagnostic.location, diagnostic.identifier
+        )?;
+        if let Some(suggestion) = &diagnostic.suggestion {
+            write!(f, " to `{}`", suggestion)?;
+        }
+        write!(f, ": {}", self.reason)
+    }
+}
+
+/// A source file with renamed identifiers.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FileFix {
+    pub path: PathBuf,
+    pub original: String,
+    pub fixed: String,
+}
+
+impl FileFix {
+    /// Get a unified diff from the original content to the fixed one.
+    ///
+    /// Lines are compared one by one, as renames never add or remove lines.
+    pub fn diff(&self) -> String {
+        let old: Vec<&str> = self.original.split_inclusive('\n').collect();
+        let new: Vec<&str> = self.fixed.split_inclusive('\n').collect();
+        let len = old.len().max(new.len());
+        let changed: Vec<usize> =
+            (0..len).filter(|&i| old.get(i) != new.get(i)).collect();
+        if changed.is_empty() {
+            return String::new();
+        }
+
+        let path = self.path.display();
+        let mut diff = format!("--- {}\n+++ {}\n", path, path);
+        let mut i = 0;
+        while i < changed.len() {
+            // Changes with overlapping context are in the same hunk.
+            let mut j = i;
+            while j + 1 < changed.len()
+                && changed[j + 1] - changed[j] <= 2 * CONTEXT
+            {
+                j += 1;
+            }
+            let start = changed[i].saturating_sub(CONTEXT);
+            let end = (changed[j] + CONTEXT + 1).min(len);
+            diff += &hunk(&old, &new, start, end);
+            i = j + 1;
+        }
+        diff
+    }
+
+    /// Write the fixed content to the file.
+    ///
+    /// # Errors
+    ///
+    /// Get an [io::Error] if the file can't be written.
+    pub fn write(&self) -> io::Result<()> {
+        fs::write(&self.path, &self.fixed)
+    }
+}
+
+/// What a [Fixer] found.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Fixes {
+    /// Changed files, in the order they are added.
+    pub files: Vec<FileFix>,
+    /// Violations fixed by renaming.
+    pub fixed: Vec<Diagnostic>,
+    pub refused: Vec<Refusal>,
+}
+
+#[derive(Clone, Debug)]
+struct Source {
+    path: PathBuf,
+    language: Language,
+    text: String,
+    diagnostics: Vec<Diagnostic>,
+}
+
+/// Rename identifiers that break naming conventions
+/// to their suggestions, with every reference of them.
+///
+/// Identifiers are renamed where their text is the same,
+/// in the file declaring them or in every added file,
+/// see [Scope]. A rename is refused if the new name is
+/// already used there, another identifier is renamed to it too,
+/// or the identifier is also declared with a valid name
+/// or a different suggestion.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use naming_lib::language::Language;
+/// use naming_lib::lint::{Fixer, Linter, Scope};
+///
+/// let source = "\
+/// def getUserName(user):
+///     return user.name
+///
+/// def getUser(user_id):
+///     pass
+///
+/// def get_user():
+///     return getUserName(None)
+/// ";
+/// let mut fixer = Fixer::new(Scope::File);
+/// fixer.add_source(&Linter::default(), Path::new("user.py"), Language::Python, source);
+/// let fixes = fixer.fix();
+///
+/// assert_eq!(
+///     source.replace("getUserName", "get_user_name"),
+///     fixes.files[0].fixed
+/// );
+/// assert_eq!(
+///     "user.py:4:5: can't rename `getUser` to `get_user`: \
+///      Collides with an existing name.",
+///     fixes.refused[0].to_string()
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Fixer {
+    scope: Scope,
+    sources: Vec<Source>,
+}
+
+impl Fixer {
+    /// Create a fixer renaming references in a scope.
+    pub fn new(scope: Scope) -> Fixer {
+        Fixer { scope, sources: Vec::new() }
+    }
+
+    /// Add the source code of a file, checked by a linter.
+    pub fn add_source(
+        &mut self,
+        linter: &Linter,
+        path: &Path,
+        language: Language,
+        source: &str,
+    ) {
+        self.sources.push(Source {
+            path: path.to_path_buf(),
+            language,
+            text: source.to_string(),
+            diagnostics: linter.lint_source(path, language, source),
+        });
+    }
+
+    /// Add a source file, or every source file in a directory,
+    /// walked like [lint_path()](Linter::lint_path()).
+    ///
+    /// # Errors
+    ///
+    /// Get an [io::Error] if any file or directory can't be read,
+    /// any source file isn't UTF-8, or any config file is bad.
+    pub fn add_path(&mut self, linter: &Linter, path: &Path) -> io::Result<()> {
+        linter.walk(path, &fs::canonicalize(path)?, &mut |linter, file| {
+            if let Some(language) = language_of(file) {
+                let text = fs::read_to_string(file)?;
+                self.add_source(linter, file, language, &text);
+            }
+            Ok(())
+        })
+    }
+
+    /// Rename identifiers in the added sources,
+    /// files are left as they are.
+    pub fn fix(&self) -> Fixes {
+        let units: Vec<Vec<usize>> = match self.scope {
+            Scope::File => (0..self.sources.len()).map(|i| vec![i]).collect(),
+            Scope::Project => vec![(0..self.sources.len()).collect()],
+        };
+
+        let mut fixes = Fixes::default();
+        let mut renames = vec![HashMap::new(); self.sources.len()];
+        for unit in &units {
+            let refusals = self.plan(unit);
+            for &i in unit {
+                for diagnostic in &self.sources[i].diagnostics {
+                    let target = diagnostic
+                        .suggestion
+                        .as_ref()
+                        .map(|s| unescape(diagnostic.language, s).to_string());
+                    match (refusals.get(diagnostic.identifier.as_str()), target)
+                    {
+                        (None, Some(target)) => {
+                            for &j in unit {
+                                renames[j].insert(
+                                    diagnostic.identifier.as_str(),
+                                    target.clone(),
+                                );
+                            }
+                            fixes.fixed.push(diagnostic.clone());
+                        }
+                        (reason, _) => fixes.refused.push(Refusal {
+                            diagnostic: diagnostic.clone(),
+                            reason: reason.copied().unwrap_or("No suggestion."),
+                        }),
+                    }
+                }
+            }
+        }
+
+        for (source, renames) in self.sources.iter().zip(&renames) {
+            if renames.is_empty() {
+                continue;
+            }
+            let fixed = rename(source, renames);
+            if fixed != source.text {
+                fixes.files.push(FileFix {
+                    path: source.path.clone(),
+                    original: source.text.clone(),
+                    fixed,
+                });
+            }
+        }
+        fixes
+    }
+
+    /// Get why identifiers declared in sources of a unit can't be renamed.
+    fn plan(&self, unit: &[usize]) -> HashMap<&str, &'static str> {
+        let mut names = HashSet::new();
+        // Declarations of each name, and how many of them break conventions.
+        let mut declarations: HashMap<&str, (usize, usize)> = HashMap::new();
+        let mut targets: BTreeMap<&str, HashSet<&str>> = BTreeMap::new();
+        for &i in unit {
+            let source = &self.sources[i];
+            for token in Lexer::new(source.language, &source.text) {
+                names.insert(token.text);
+                if token.kind.is_some() {
+                    declarations.entry(token.text).or_default().0 += 1;
+                }
+            }
+            for diagnostic in &source.diagnostics {
+                let identifier = diagnostic.identifier.as_str();
+                declarations.entry(identifier).or_default().1 += 1;
+                if let Some(suggestion) = &diagnostic.suggestion {
+                    targets
+                        .entry(identifier)
+                        .or_default()
+                        .insert(unescape(diagnostic.language, suggestion));
+                }
+            }
+        }
+
+        let mut claims: HashMap<&str, usize> = HashMap::new();
+        for target in targets.values().flatten() {
+            *claims.entry(target).or_default() += 1;
+        }
+
+        let mut refusals = HashMap::new();
+        for (identifier, targets) in &targets {
+            let target = targets.iter().next().unwrap();
+            let (declared, broken) = declarations[identifier];
+            let reason = if targets.len() > 1 {
+                "Declared with different suggestions."
+            } else if declared > broken {
+                "Also declared with a valid name."
+            } else if names.contains(target) {
+                "Collides with an existing name."
+            } else if claims[target] > 1 {
+                "Collides with another rename."
+            } else {
+                continue;
+            };
+            refusals.insert(*identifier, reason);
+        }
+        refusals
+    }
+}
+
+/// Replace every renamed identifier in a source.
+fn rename(source: &Source, renames: &HashMap<&str, String>) -> String {
+    let text = &source.text;
+    let mut fixed = String::with_capacity(text.len());
+    let mut last = 0;
+    for token in Lexer::new(source.language, text) {
+        let target = match renames.get(token.text) {
+            Some(target) => target,
+            None => continue,
+        };
+        fixed += &text[last..token.span.start];
+        // The escape around the span is kept.
+        if is_escaped(source.language, text, token.span) {
+            fixed += target;
+        } else {
+            fixed += &source.language.escape(target);
+        }
+        last = token.span.end;
+    }
+    fixed + &text[last..]
+}
+
+/// Check if an identifier is escaped, like `r#type` in Rust.
+fn is_escaped(language: Language, text: &str, span: Span) -> bool {
+    let (before, after) = (&text[..span.start], &text[span.end..]);
+    match language {
+        Language::Rust => before.ends_with("r#"),
+        Language::CSharp => before.ends_with('@'),
+        _ => ['`', '"']
+            .iter()
+            .any(|quote| before.ends_with(*quote) && after.starts_with(*quote)),
+    }
+}
+
+/// Remove the escape added by [escape()](Language::escape()).
+fn unescape(language: Language, identifier: &str) -> &str {
+    let stripped = match language {
+        Language::Rust => identifier.strip_prefix("r#"),
+        Language::CSharp => identifier.strip_prefix('@'),
+        _ => ['`', '"'].iter().find_map(|quote| {
+            identifier.strip_prefix(*quote)?.strip_suffix(*quote)
+        }),
+    };
+    stripped.unwrap_or(identifier)
+}
+
+/// Get a hunk of lines in a range, of both versions.
+fn hunk(old: &[&str], new: &[&str], start: usize, end: usize) -> String {
+    let count = |lines: &[&str]| end.min(lines.len()).saturating_sub(start);
+    let (old_count, new_count) = (count(old), count(new));
+    // Empty ranges start at the line before.
+    let first = |count: usize| if count == 0 { start } else { start + 1 };
+    let mut hunk = format!(
+        "@@ -{},{} +{},{} @@\n",
+        first(old_count),
+        old_count,
+        first(new_count),
+        new_count
+    );
+
+    let mut i = start;
+    while i < end {
+        if old.get(i) == new.get(i) {
+            push_line(&mut hunk, ' ', old[i]);
+            i += 1;
+            continue;
+        }
+        let run = i;
+        while i < end && old.get(i) != new.get(i) {
+            i += 1;
+        }
+        for line in old.iter().take(i).skip(run) {
+            push_line(&mut hunk, '-', line);
+        }
+        for line in new.iter().take(i).skip(run) {
+            push_line(&mut hunk, '+', line);
+        }
+    }
+    hunk
+}
+
+fn push_line(hunk: &mut String, mark: char, line: &str) {
+    hunk.push(mark);
+    hunk.push_str(line);
+    if !line.ends_with('\n') {
+        hunk.push_str("\n\\ No newline at end of file\n");
+    }
+}
@@ -0,0 +1,351 @@
+//! Check naming conventions of identifiers in source trees.
+//!
+//! Declared identifiers are found by a [Lexer] in every source file
+//! of a known language (see [from_extension()](Language::from_extension())),
+//! classified with [which_case()](crate::which_case()),
+//! and checked against the conventions of a [Preset].
+//!
+//! # Examples
+//!
+//! ```
+//! use std::path::Path;
+//!
+//! use naming_lib::language::{Language, SymbolKind};
+//! use naming_lib::lint::Linter;
+//! use naming_lib::{CaseKind, TargetCase};
+//!
+//! let source = "fn parseArgs() {}\nstruct Config;\n";
+//! let diagnostics =
+//!     Linter::default().lint_source(Path::new("main.rs"), Language::Rust, source);
+//!
+//! assert_eq!(1, diagnostics.len());
+//! let diagnostic = &diagnostics[0];
+//! assert_eq!("parseArgs", diagnostic.identifier);
+//! assert_eq!(SymbolKind::Function, diagnostic.kind);
+//! assert_eq!(CaseKind::Camel, diagnostic.detected);
+//! assert_eq!(TargetCase::Snake, diagnostic.expected);
+//! assert_eq!((1, 4), (diagnostic.location.line, diagnostic.location.column));
+//! assert_eq!(
+//!     "main.rs:1:4: function `parseArgs` should be snake case, found camel (try `parse_args`)",
+//!     diagnostic.to_string()
+//! );
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::language::{expected_case, Convention, Language, SymbolKind};
+use crate::lexer::Lexer;
+use crate::{detector, CaseKind, TargetCase};
+
+pub use crate::language::AcronymPolicy;
+#[cfg(feature = "toml")]
+pub use config::*;
+pub use fix::*;
+pub use report::*;
+pub use rules::*;
+
+#[cfg(feature = "toml")]
+mod config;
+mod fix;
+mod report;
+mod rules;
+
+/// Where an identifier is found.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Location {
+    pub path: PathBuf,
+    /// 1-based.
+    pub line: usize,
+    /// 1-based, counted in characters.
+    pub column: usize,
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}:{}:{}", self.path.display(), self.line, self.column)
+    }
+}
+
+/// An identifier that breaks the naming convention.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Diagnostic {
+    pub location: Location,
+    pub language: Language,
+    pub identifier: String,
+    pub kind: SymbolKind,
+    /// The case it is written in.
+    pub detected: CaseKind,
+    /// The case it should be written in.
+    pub expected: TargetCase,
+    /// See [Violation](crate::language::Violation).
+    pub suggestion: Option<String>,
+}
+
+impl Diagnostic {
+    /// Describe the violation, without the location.
+    pub fn message(&self) -> String {
+        let mut message = format!(
+            "{} `{}` should be {} case, found {}",
+            self.kind, self.identifier, self.expected, self.detected
+        );
+        if let Some(suggestion) = &self.suggestion {
+            message += &format!(" (try `{}`)", suggestion);
+        }
+        message
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}: {}", self.location, self.message())
+    }
+}
+
+/// Conventions of every language and kind of symbol.
+///
+/// The [default()](Preset::default()) one follows
+/// [expected_case()](crate::language::expected_case()),
+/// the common style guides, and can be overridden.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Preset {
+    overrides: HashMap<(Language, SymbolKind), Option<Convention>>,
+}
+
+impl Preset {
+    /// Follow the common style guide of every language.
+    pub fn standard() -> Preset {
+        Preset::default()
+    }
+
+    /// Replace the convention of a kind of symbol in a language,
+    /// [None] to not check it.
+    pub fn set(
+        &mut self,
+        language: Language,
+        kind: SymbolKind,
+        convention: Option<Convention>,
+    ) {
+        self.overrides.insert((language, kind), convention);
+    }
+
+    /// Get the convention of a kind of symbol in a language.
+    pub fn convention(
+        &self,
+        language: Language,
+        kind: SymbolKind,
+    ) -> Option<Convention> {
+        match self.overrides.get(&(language, kind)) {
+            Some(convention) => *convention,
+            None => expected_case(language, kind),
+        }
+    }
+}
+
+/// Check identifiers in source files against [Rules].
+#[derive(Clone, Debug, Default)]
+pub struct Linter {
+    rules: Rules,
+}
+
+impl Linter {
+    /// Create a linter with the given conventions.
+    pub fn new(preset: Preset) -> Linter {
+        let mut rules = Rules::standard();
+        rules.preset = preset;
+        Linter::with_rules(rules)
+    }
+
+    /// Create a linter with the given rules.
+    pub fn with_rules(rules: Rules) -> Linter {
+        Linter { rules }
+    }
+
+    /// Create a linter with rules of [config files](Config)
+    /// in directories containing the path, from the outermost one,
+    /// or from the innermost one with `root = true`.
+    ///
+    /// Config files in the path itself and its subdirectories
+    /// are read while [walking](Linter::lint_path()).
+    ///
+    /// # Errors
+    ///
+    /// Get a [ConfigError] if the path doesn't exist,
+    /// or any config file is bad.
+    #[cfg(feature = "toml")]
+    pub fn discover(path: &Path) -> Result<Linter, ConfigError> {
+        let absolute = fs::canonicalize(path).map_err(|e| ConfigError {
+            path: Some(path.to_path_buf()),
+            key: None,
+            message: e.to_string(),
+        })?;
+
+        let mut configs = Vec::new();
+        for directory in absolute.ancestors().skip(1) {
+            let file = directory.join(CONFIG_FILE_NAME);
+            if file.is_file() {
+                let config = Config::read(&file)?;
+                let root = config.root;
+                configs.push((config, directory));
+                if root {
+                    break;
+                }
+            }
+        }
+
+        let mut rules = Rules::standard();
+        for (config, directory) in configs.iter().rev() {
+            config.apply(&mut rules, directory).map_err(|e| ConfigError {
+                path: Some(directory.join(CONFIG_FILE_NAME)),
+                ..e
+            })?;
+        }
+        Ok(Linter::with_rules(rules))
+    }
+
+    /// Get the conventions in use.
+    pub fn preset(&self) -> &Preset {
+        &self.rules.preset
+    }
+
+    /// Get the rules in use.
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    /// Check the source code of a file,
+    /// the path is only used in locations.
+    pub fn lint_source(
+        &self,
+        path: &Path,
+        language: Language,
+        source: &str,
+    ) -> Vec<Diagnostic> {
+        Lexer::new(language, source)
+            .filter_map(|token| {
+                let kind = token.kind?;
+                let violation =
+                    self.rules.check(language, kind, token.text).err()?;
+                Some(Diagnostic {
+                    location: Location {
+                        path: path.to_path_buf(),
+                        line: token.span.line,
+                        column: token.span.column,
+                    },
+                    language,
+                    identifier: token.text.to_string(),
+                    kind,
+                    detected: detector::which_case(token.text).kind(),
+                    expected: violation.expected,
+                    suggestion: violation.suggestion,
+                })
+            })
+            .collect()
+    }
+
+    /// Check a source file, nothing is found
+    /// if its language is unknown.
+    ///
+    /// Bytes out of UTF-8 are replaced before checking.
+    ///
+    /// # Errors
+    ///
+    /// Get an [io::Error] if the file can't be read.
+    pub fn lint_file(&self, path: &Path) -> io::Result<Vec<Diagnostic>> {
+        let language = match language_of(path) {
+            Some(language) => language,
+            None => return Ok(Vec::new()),
+        };
+        let bytes = fs::read(path)?;
+        Ok(self.lint_source(path, language, &String::from_utf8_lossy(&bytes)))
+    }
+
+    /// Check a source file, or every source file in a directory recursively,
+    /// in the order of their paths.
+    ///
+    /// Hidden files and directories, whose names start with `.`,
+    /// symbolic links and [ignored](Rules::is_ignored()) paths
+    /// are skipped while walking.
+    /// With the `toml` feature, [config files](Config) in walked directories
+    /// apply to them and their subdirectories.
+    ///
+    /// # Errors
+    ///
+    /// Get an [io::Error] if any file or directory can't be read,
+    /// or any config file is bad.
+    pub fn lint_path(&self, path: &Path) -> io::Result<Vec<Diagnostic>> {
+        let mut diagnostics = Vec::new();
+        self.walk(path, &fs::canonicalize(path)?, &mut |linter, file| {
+            diagnostics.append(&mut linter.lint_file(file)?);
+            Ok(())
+        })?;
+        Ok(diagnostics)
+    }
+
+    /// Visit every file with the linter of its directory,
+    /// with the path as given for locations,
+    /// and the canonical one for ignore patterns.
+    fn walk<F>(
+        &self,
+        path: &Path,
+        absolute: &Path,
+        visit: &mut F,
+    ) -> io::Result<()>
+    where
+        F: FnMut(&Linter, &Path) -> io::Result<()>,
+    {
+        if self.rules.is_ignored(absolute) {
+            return Ok(());
+        }
+        if !fs::metadata(path)?.is_dir() {
+            return visit(self, path);
+        }
+
+        #[cfg(feature = "toml")]
+        let nested;
+        #[cfg(feature = "toml")]
+        let linter = {
+            let file = path.join(CONFIG_FILE_NAME);
+            if file.is_file() {
+                let invalid = |e| io::Error::new(io::ErrorKind::InvalidData, e);
+                let config = Config::read(&file).map_err(invalid)?;
+                let mut rules = self.rules.clone();
+                config.apply(&mut rules, absolute).map_err(|e| {
+                    invalid(ConfigError { path: Some(file.clone()), ..e })
+                })?;
+                nested = Linter::with_rules(rules);
+                &nested
+            } else {
+                self
+            }
+        };
+        #[cfg(not(feature = "toml"))]
+        let linter = self;
+
+        let mut entries =
+            fs::read_dir(path)?.collect::<io::Result<Vec<_>>>()?;
+        entries.sort_by_key(|entry| entry.file_name());
+
+        for entry in entries {
+            let name = entry.file_name();
+            if name.to_string_lossy().starts_with('.') {
+                continue;
+            }
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() || file_type.is_file() {
+                linter.walk(&entry.path(), &absolute.join(&name), visit)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Get the language of a source file from its extension.
+fn language_of(path: &Path) -> Option<Language> {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .and_then(Language::from_extension)
+}
@@ -0,0 +1,329 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use super::Diagnostic;
+use crate::json::json_string;
+use crate::language::SymbolKind;
+
+/// Formats of lint reports.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Format {
+    /// A line per diagnostic, see [Diagnostic]'s [Display] implementation.
+    Text,
+    /// A JSON object per line.
+    Json,
+    /// A SARIF 2.1.0 log, for code scanning.
+    Sarif,
+    /// A Checkstyle XML report.
+    Checkstyle,
+    /// Workflow commands that annotate code in GitHub Actions.
+    Github,
+}
+
+impl Format {
+    pub const ALL: [Format; 5] = [
+        Format::Text,
+        Format::Json,
+        Format::Sarif,
+        Format::Checkstyle,
+        Format::Github,
+    ];
+
+    /// Get the name, like "sarif".
+    pub fn name(self) -> &'static str {
+        match self {
+            Format::Text => "text",
+            Format::Json => "json",
+            Format::Sarif => "sarif",
+            Format::Checkstyle => "checkstyle",
+            Format::Github => "github",
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Format {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Format::ALL
+            .iter()
+            .copied()
+            .find(|format| format.name() == s)
+            .ok_or("Unknown report format name.")
+    }
+}
+
+/// Write diagnostics in a format.
+///
+/// Every format has the detected case, the expected case
+/// and the suggestion of each diagnostic,
+/// as fields or in messages like the [Text](Format::Text) one.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use naming_lib::language::Language;
+/// use naming_lib::lint::{report, Format, Linter};
+///
+/// let diagnostics =
+///     Linter::default().lint_source(Path::new("main.rs"), Language::Rust, "fn parseArgs() {}");
+/// let mut out = Vec::new();
+/// report(&diagnostics, Format::Github, &mut out).unwrap();
+///
+/// assert_eq!(
+///     "::warning file=main.rs,line=1,col=4,endColumn=13,title=naming/function::\
+///      function `parseArgs` should be snake case, found camel (try `parse_args`)\n",
+///     String::from_utf8(out).unwrap()
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Get an [io::Error] if writing fails.
+pub fn report(
+    diagnostics: &[Diagnostic],
+    format: Format,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    match format {
+        Format::Text => {
+            for diagnostic in diagnostics {
+                writeln!(out, "{}", diagnostic)?;
+            }
+            Ok(())
+        }
+        Format::Json => {
+            for diagnostic in diagnostics {
+                writeln!(out, "{}", json_line(diagnostic))?;
+            }
+            Ok(())
+        }
+        Format::Sarif => writeln!(out, "{}", sarif(diagnostics)),
+        Format::Checkstyle => write!(out, "{}", checkstyle(diagnostics)),
+        Format::Github => {
+            for diagnostic in diagnostics {
+                writeln!(out, "{}", github(diagnostic))?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn rule_id(kind: SymbolKind) -> String {
+    format!("naming/{}", kind)
+}
+
+/// The column after the identifier.
+fn end_column(diagnostic: &Diagnostic) -> usize {
+    diagnostic.location.column + diagnostic.identifier.chars().count()
+}
+
+fn path_of(diagnostic: &Diagnostic) -> String {
+    diagnostic.location.path.to_string_lossy().replace('\\', "/")
+}
+
+fn json_line(diagnostic: &Diagnostic) -> String {
+    format!(
+        "{{\"path\":{},\"line\":{},\"column\":{},\"language\":{},\
+         \"identifier\":{},\"kind\":{},\"case\":{},\"expected\":{},\
+         \"suggestion\":{}}}",
+        json_string(&diagnostic.location.path.to_string_lossy()),
+        diagnostic.location.line,
+        diagnostic.location.column,
+        json_string(diagnostic.language.name()),
+        json_string(&diagnostic.identifier),
+        json_string(diagnostic.kind.name()),
+        json_string(diagnostic.detected.name()),
+        json_string(diagnostic.expected.name()),
+        json_option(&diagnostic.suggestion)
+    )
+}
+
+fn sarif(diagnostics: &[Diagnostic]) -> String {
+    let kinds: Vec<SymbolKind> = SymbolKind::ALL
+        .iter()
+        .copied()
+        .filter(|kind| diagnostics.iter().any(|d| d.kind == *kind))
+        .collect();
+    let rules: Vec<String> = kinds
+        .iter()
+        .map(|kind| {
+            format!(
+                "{{\"id\":{},\"shortDescription\":{{\"text\":{}}}}}",
+                json_string(&rule_id(*kind)),
+                json_string(&format!("Names of {} declarations", kind))
+            )
+        })
+        .collect();
+    let results: Vec<String> = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let uri = json_string(&uri_of(diagnostic));
+            let region = format!(
+                "{{\"startLine\":{},\"startColumn\":{},\"endColumn\":{}}}",
+                diagnostic.location.line,
+                diagnostic.location.column,
+                end_column(diagnostic)
+            );
+            let fixes = match &diagnostic.suggestion {
+                Some(suggestion) => format!(
+                    ",\"fixes\":[{{\"description\":{{\"text\":{}}},\
+                     \"artifactChanges\":[{{\"artifactLocation\":{{\"uri\":{}}},\
+                     \"replacements\":[{{\"deletedRegion\":{},\
+                     \"insertedContent\":{{\"text\":{}}}}}]}}]}}]",
+                    json_string(&format!("Rename to `{}`", suggestion)),
+                    uri,
+                    region,
+                    json_string(suggestion)
+                ),
+                None => String::new(),
+            };
+            format!(
+                "{{\"ruleId\":{},\"ruleIndex\":{},\"level\":\"warning\",\
+                 \"message\":{{\"text\":{}}},\
+                 \"locations\":[{{\"physicalLocation\":{{\
+                 \"artifactLocation\":{{\"uri\":{}}},\"region\":{}}}}}],\
+                 \"properties\":{{\"identifier\":{},\"language\":{},\
+                 \"detected\":{},\"expected\":{},\"suggestion\":{}}}{}}}",
+                json_string(&rule_id(diagnostic.kind)),
+                kinds.iter().position(|kind| *kind == diagnostic.kind).unwrap(),
+                json_string(&diagnostic.message()),
+                uri,
+                region,
+                json_string(&diagnostic.identifier),
+                json_string(diagnostic.language.name()),
+                json_string(diagnostic.detected.name()),
+                json_string(diagnostic.expected.name()),
+                json_option(&diagnostic.suggestion),
+                fixes
+            )
+        })
+        .collect();
+
+    format!(
+        "{{\"$schema\":\"https://json.schemastore.org/sarif-2.1.0.json\",\
+         \"version\":\"2.1.0\",\"runs\":[{{\"tool\":{{\"driver\":{{\
+         \"name\":\"naming\",\"version\":{},\"informationUri\":{},\
+         \"rules\":[{}]}}}},\"columnKind\":\"unicodeCodePoints\",\
+         \"results\":[{}]}}]}}",
+        json_string(env!("CARGO_PKG_VERSION")),
+        json_string(env!("CARGO_PKG_REPOSITORY")),
+        rules.join(","),
+        results.join(",")
+    )
+}
+
+fn checkstyle(diagnostics: &[Diagnostic]) -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <checkstyle version=\"4.3\">\n",
+    );
+    // Files in the order they first appear.
+    let mut paths: Vec<&std::path::Path> = Vec::new();
+    for diagnostic in diagnostics {
+        if !paths.contains(&diagnostic.location.path.as_path()) {
+            paths.push(&diagnostic.location.path);
+        }
+    }
+    for path in paths {
+        xml += &format!(
+            "  <file name=\"{}\">\n",
+            xml_escape(&path.to_string_lossy())
+        );
+        for diagnostic in diagnostics.iter().filter(|d| d.location.path == path)
+        {
+            xml += &format!(
+                "    <error line=\"{}\" column=\"{}\" severity=\"warning\" \
+                 message=\"{}\" source=\"{}\"/>\n",
+                diagnostic.location.line,
+                diagnostic.location.column,
+                xml_escape(&diagnostic.message()),
+                xml_escape(&rule_id(diagnostic.kind))
+            );
+        }
+        xml += "  </file>\n";
+    }
+    xml + "</checkstyle>\n"
+}
+
+fn github(diagnostic: &Diagnostic) -> String {
+    format!(
+        "::warning file={},line={},col={},endColumn={},title={}::{}",
+        github_property(&path_of(diagnostic)),
+        diagnostic.location.line,
+        diagnostic.location.column,
+        end_column(diagnostic),
+        github_property(&rule_id(diagnostic.kind)),
+        github_data(&diagnostic.message())
+    )
+}
+
+fn github_data(s: &str) -> String {
+    s.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+fn github_property(s: &str) -> String {
+    github_data(s).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Get a relative reference, or a `file` URI of an absolute path,
+/// percent-encoding characters that can't be in it.
+fn uri_of(diagnostic: &Diagnostic) -> String {
+    let path = path_of(diagnostic);
+    let absolute = diagnostic.location.path.is_absolute();
+    let mut uri = match (absolute, path.starts_with('/')) {
+        (true, true) => String::from("file://"),
+        // Like `C:/`.
+        (true, false) => String::from("file:///"),
+        (false, _) => String::new(),
+    };
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'/'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b'~' => uri.push(byte as char),
+            b':' if absolute => uri.push(':'),
+            _ => uri += &format!("%{:02X}", byte),
+        }
+    }
+    uri
+}
+
+fn json_option(s: &Option<String>) -> String {
+    match s {
+        Some(s) => json_string(s),
+        None => "null".to_string(),
+    }
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' | '\r' | '\t' => escaped.push_str(&format!("&#{};", c as u32)),
+            // Not allowed in XML 1.0.
+            c if (c as u32) < 0x20 => escaped.push('\u{fffd}'),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,169 @@
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use super::Preset;
+use crate::language::{
+    check_with, AcronymPolicy, CheckOptions, Language, SymbolKind, Violation,
+};
+
+/// A glob of paths to skip, relative to a base directory.
+#[derive(Clone, Debug)]
+struct Ignore {
+    base: PathBuf,
+    regex: Regex,
+    /// Patterns without `/` match names at any depth, like `target`.
+    anchored: bool,
+}
+
+/// Everything the linter checks against:
+/// conventions, how words are told apart and which paths are skipped.
+///
+/// Create one with [Rules::default()] and change its fields as needed,
+/// or read them from [config files](super::Config).
+#[derive(Clone, Debug, Default)]
+pub struct Rules {
+    pub preset: Preset,
+    /// Default is [Preserve](AcronymPolicy::Preserve).
+    pub acronyms: AcronymPolicy,
+    /// Words that keep their spelling in every case, like `iOS` or `macOS`.
+    pub exceptions: Vec<String>,
+    ignores: Vec<Ignore>,
+}
+
+impl Rules {
+    /// Follow the common style guides, without exceptions or ignored paths.
+    pub fn standard() -> Rules {
+        Rules::default()
+    }
+
+    /// Check if an identifier follows the convention of its kind.
+    ///
+    /// Like [check()](crate::language::check()) but with
+    /// the conventions of the [Preset],
+    /// the [acronym policy](Rules::acronyms) and [exceptions](Rules::exceptions).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use naming_lib::language::{Language, SymbolKind};
+    /// use naming_lib::lint::{AcronymPolicy, Rules};
+    ///
+    /// let mut rules = Rules::standard();
+    /// assert!(rules.check(Language::Java, SymbolKind::Type, "HTTPServer").is_ok());
+    ///
+    /// let violation =
+    ///     rules.check(Language::Rust, SymbolKind::Function, "parseHTTPRequest");
+    /// assert_eq!(Some("parse_http_request".to_string()), violation.unwrap_err().suggestion);
+    ///
+    /// rules.acronyms = AcronymPolicy::Capitalize;
+    /// let violation = rules.check(Language::Java, SymbolKind::Type, "HTTPServer");
+    /// assert_eq!(Some("HttpServer".to_string()), violation.unwrap_err().suggestion);
+    ///
+    /// rules.exceptions.push("iOS".to_string());
+    /// assert!(rules.check(Language::Rust, SymbolKind::Function, "is_iOS_app").is_ok());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Get a [Violation] if the identifier isn't in the expected case.
+    pub fn check(
+        &self,
+        language: Language,
+        kind: SymbolKind,
+        identifier: &str,
+    ) -> Result<(), Violation> {
+        match self.preset.convention(language, kind) {
+            Some(convention) => check_with(
+                language,
+                convention,
+                identifier,
+                &CheckOptions {
+                    acronyms: self.acronyms,
+                    exceptions: &self.exceptions,
+                },
+            ),
+            None => Ok(()),
+        }
+    }
+
+    /// Skip paths matching a glob, relative to a base directory,
+    /// which should be canonical, see [canonicalize()](std::fs::canonicalize()).
+    ///
+    /// `*` matches anything but `/`, `**` matches anything
+    /// and `?` matches one character.
+    /// Patterns without `/`, like `target` or `*.pb.go`,
+    /// match names of files and directories at any depth under the base,
+    /// and everything in those directories.
+    /// A trailing `/` is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Get an [Err](core::result::Result::Err) if the pattern is empty.
+    pub fn add_ignore(
+        &mut self,
+        base: &Path,
+        pattern: &str,
+    ) -> Result<(), &'static str> {
+        let pattern = pattern.trim_end_matches('/');
+        let anchored = pattern.contains('/');
+        let pattern = pattern.trim_start_matches('/');
+        if pattern.is_empty() {
+            return Err("Empty ignore pattern.");
+        }
+        self.ignores.push(Ignore {
+            base: base.to_path_buf(),
+            regex: glob_to_regex(pattern),
+            anchored,
+        });
+        Ok(())
+    }
+
+    /// Check if a path, or any directory containing it,
+    /// is skipped by an ignore pattern.
+    ///
+    /// The path is compared with base directories as it is,
+    /// the linter gives canonical paths.
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        self.ignores.iter().any(|ignore| {
+            let relative = match path.strip_prefix(&ignore.base) {
+                Ok(relative) => relative,
+                Err(_) => return false,
+            };
+            let names: Vec<_> = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect();
+            if ignore.anchored {
+                (1..=names.len())
+                    .any(|len| ignore.regex.is_match(&names[..len].join("/")))
+            } else {
+                names.iter().any(|name| ignore.regex.is_match(name))
+            }
+        })
+    }
+}
+
+fn glob_to_regex(pattern: &str) -> Regex {
+    let mut regex = String::from("^");
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                // `a/**/b` also matches `a/b`.
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    regex.push_str("(?:.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+    Regex::new(&regex).unwrap()
+}
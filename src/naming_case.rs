@@ -1,9 +1,16 @@
+use std::borrow::{Borrow, Cow};
+use std::cmp::Ordering;
+use std::convert::Infallible;
 use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 
 use lazy_static::lazy_static;
 use regex::Regex;
 
 use crate::detector;
+use crate::hungarian::PrefixDictionary;
+use crate::language::Language;
 
 /// Indicates which format the string belongs to,
 /// and acts as an intermediary between format conversions.
@@ -21,47 +28,35 @@ use crate::detector;
 /// let third = from("identifier");
 /// ```
 ///
-/// ### Notice
-///
-/// Of course you can generate instances of a specific enum type directly,
-/// I can't stop you
-/// (in fact [there is a solution](https://stackoverflow.com/a/28090996/11397457),
-/// but it makes things more complex),
-/// but I **don't recommend using this approach**.
+/// Instances can't be created in other ways,
+/// so the [kind()](NamingCase::kind()) of an instance
+/// always matches the string it holds.
 ///
 /// ```
-/// use naming_lib::NamingCase;
+/// use naming_lib::{from, CaseKind};
 ///
-/// let direct_instance = NamingCase::Invalid("text".to_string());
+/// assert_eq!(CaseKind::Camel, from("fooBar").kind());
+/// assert_eq!(CaseKind::Invalid, from("foo bar").kind());
 /// ```
 ///
-/// I can't do an input valid check when you use this approach,
-/// type-related methods on these instances **may cause unexpected panic**.
-///
-/// I currently use this myself to:
-///
-/// 1. write document test cases
-/// (have to use this to "clearly express the state of the test values").
-///
-/// 2. generated instances of [Invalid](NamingCase::Invalid) enum type
-/// (it's safe, because conversion methods cannot be called on this enum type,
-/// there are no other type-related methods available now).
-///
 /// ## Get Origin String From An Instance
 ///
 /// A [NamingCase] instance holds the given string value when created,
-/// which can be got by calling [to_string()](std::string::ToString).
+/// which can be borrowed by calling [as_str()](NamingCase::as_str()),
+/// or taken by calling [into_inner()](NamingCase::into_inner())
+/// or [String::from()].
 ///
 /// ```
 /// use naming_lib::from;
 ///
-/// assert_eq!("example",from("example").to_string())
+/// assert_eq!("example", from("example").as_str());
+/// assert_eq!("example", String::from(from("example")));
 /// ```
 ///
 /// ## Convert An Instance To Other Naming Case String
 ///
 /// A [NamingCase] instance also can be converted to a string in another naming format,
-/// as long as it's not the [Invalid](NamingCase::Invalid) enum.
+/// as long as it's not [Invalid](CaseKind::Invalid).
 ///
 /// ```
 /// use naming_lib::from;
@@ -73,66 +68,287 @@ use crate::detector;
 ///
 /// For ease of use,
 /// instead of implementing the conversion methods
-/// with [Invalid](NamingCase::Invalid) excluded,
+/// with [Invalid](CaseKind::Invalid) excluded,
 /// I have chosen that all conversion methods
 /// will return the [Result](core::result) type.
 ///
-/// Calling any conversion method on an [Invalid](NamingCase::Invalid) enum
+/// Calling any conversion method on an [Invalid](CaseKind::Invalid) instance
 /// will return an [Err](core::result::Result::Err).
-#[derive(PartialEq, Debug)]
-pub enum NamingCase {
+///
+/// If you need a value that is guaranteed to be in a certain format,
+/// use the validated types like [SnakeCase](crate::SnakeCase) instead.
+///
+/// ## Compare Instances
+///
+/// Instances are compared, ordered and hashed by the included string only,
+/// same as [str], so they can be looked up by `&str` in maps and sets.
+///
+/// ```
+/// use std::collections::HashSet;
+/// use naming_lib::NamingCase;
+///
+/// let names: HashSet<NamingCase> = ["fooBar", "foo_bar"].iter().map(|s| s.parse().unwrap()).collect();
+/// assert!(names.contains("fooBar"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct NamingCase<'a> {
+    kind: CaseKind,
+    value: Cow<'a, str>,
+}
+
+/// The format that a [NamingCase] belongs to.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum CaseKind {
     /// A single word will be recognized as multiple formats
     /// (snake, kebab, camel),
     /// so it belongs to a separate category.
-    SingleWord(String),
-    ScreamingSnake(String),
-    Snake(String),
-    Kebab(String),
-    Camel(String),
-    Pascal(String),
+    SingleWord,
+    ScreamingSnake,
+    Snake,
+    Kebab,
+    Camel,
+    Pascal,
     /// Can't be recognized as a known format.
-    Invalid(String),
+    Invalid,
+}
+
+/// The naming formats that a [NamingCase] can be converted to,
+/// for choosing a conversion at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use naming_lib::{from, TargetCase};
+///
+/// let target = TargetCase::Kebab;
+/// assert_eq!("camel-case", from("camelCase").to_case(target).unwrap());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum TargetCase {
+    ScreamingSnake,
+    Snake,
+    Kebab,
+    Camel,
+    Pascal,
 }
 
-impl Display for NamingCase {
+impl CaseKind {
+    /// Get the kebab case name of this format,
+    /// like `screaming-snake` or `single-word`.
+    pub fn name(self) -> &'static str {
+        match self {
+            CaseKind::SingleWord => "single-word",
+            CaseKind::ScreamingSnake => "screaming-snake",
+            CaseKind::Snake => "snake",
+            CaseKind::Kebab => "kebab",
+            CaseKind::Camel => "camel",
+            CaseKind::Pascal => "pascal",
+            CaseKind::Invalid => "invalid",
+        }
+    }
+}
+
+impl Display for CaseKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl TargetCase {
+    /// All target cases.
+    pub const ALL: [TargetCase; 5] = [
+        TargetCase::ScreamingSnake,
+        TargetCase::Snake,
+        TargetCase::Kebab,
+        TargetCase::Camel,
+        TargetCase::Pascal,
+    ];
+
+    /// Get the kebab case name of this format,
+    /// which can be parsed back by [from_str()](TargetCase::from_str()).
+    pub fn name(self) -> &'static str {
         match self {
-            NamingCase::SingleWord(s) => {
-                write!(f, "{}", s)
-            }
-            NamingCase::ScreamingSnake(s) => {
-                write!(f, "{}", s)
-            }
-            NamingCase::Snake(s) => {
-                write!(f, "{}", s)
-            }
-            NamingCase::Kebab(s) => {
-                write!(f, "{}", s)
-            }
-            NamingCase::Camel(s) => {
-                write!(f, "{}", s)
-            }
-            NamingCase::Pascal(s) => {
-                write!(f, "{}", s)
-            }
-            NamingCase::Invalid(s) => {
-                write!(f, "{}", s)
+            TargetCase::ScreamingSnake => "screaming-snake",
+            TargetCase::Snake => "snake",
+            TargetCase::Kebab => "kebab",
+            TargetCase::Camel => "camel",
+            TargetCase::Pascal => "pascal",
+        }
+    }
+}
+
+impl Display for TargetCase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for TargetCase {
+    type Err = &'static str;
+
+    /// Parse a name like `snake` or `screaming-snake`,
+    /// words may also be joined with `_`, like `screaming_snake`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "screaming-snake" | "screaming_snake" => {
+                Ok(TargetCase::ScreamingSnake)
             }
+            "snake" => Ok(TargetCase::Snake),
+            "kebab" => Ok(TargetCase::Kebab),
+            "camel" => Ok(TargetCase::Camel),
+            "pascal" => Ok(TargetCase::Pascal),
+            _ => Err("Unknown target case name."),
         }
     }
 }
 
-impl NamingCase {
+impl Display for NamingCase<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.value)
+    }
+}
+
+// The kind isn't compared, it's always detected from the string,
+// so equal strings have equal kinds.
+// Hashing the string only also keeps lookups by `&str` working.
+impl PartialEq for NamingCase<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for NamingCase<'_> {}
+
+impl Hash for NamingCase<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl PartialOrd for NamingCase<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NamingCase<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl FromStr for NamingCase<'static> {
+    type Err = Infallible;
+
+    /// Same as [which_case()](crate::detector::which_case()),
+    /// any string can be parsed, maybe as an [Invalid](CaseKind::Invalid) one.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(detector::which_case(s).into_owned())
+    }
+}
+
+impl AsRef<str> for NamingCase<'_> {
+    fn as_ref(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Borrow<str> for NamingCase<'_> {
+    fn borrow(&self) -> &str {
+        &self.value
+    }
+}
+
+impl From<NamingCase<'_>> for String {
+    fn from(case: NamingCase<'_>) -> String {
+        case.value.into_owned()
+    }
+}
+
+impl<'a> NamingCase<'a> {
     /// Create a [NamingCase] value from an identifier.
     ///
     /// Alias of [which_case()](crate::detector::which_case()) and [from()](crate::naming_case::from()).
-    pub fn new(identifier: &str) -> NamingCase {
+    pub fn new(identifier: &'a str) -> NamingCase<'a> {
         detector::which_case(identifier)
     }
 
-    /// Check if this is an [Invalid](NamingCase::Invalid) instance.
+    /// Only used by the detector, which has checked the identifier.
+    pub(crate) fn with_kind(
+        kind: CaseKind,
+        value: Cow<'a, str>,
+    ) -> NamingCase<'a> {
+        NamingCase { kind, value }
+    }
+
+    /// Copy the included string if it's borrowed,
+    /// so the value no longer borrows the identifier it was created from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use naming_lib::{from, NamingCase};
+    ///
+    /// let owned: NamingCase<'static> = {
+    ///     let identifier = String::from("fooBar");
+    ///     from(&identifier).into_owned()
+    /// };
+    /// assert_eq!("fooBar", owned.as_str());
+    /// ```
+    pub fn into_owned(self) -> NamingCase<'static> {
+        NamingCase {
+            kind: self.kind,
+            value: Cow::Owned(self.value.into_owned()),
+        }
+    }
+
+    /// Get the format that the included string belongs to.
+    pub fn kind(&self) -> CaseKind {
+        self.kind
+    }
+
+    /// Get the included string.
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Take the included string,
+    /// without copying it.
+    pub fn into_inner(self) -> Cow<'a, str> {
+        self.value
+    }
+
+    /// Check if this is an [Invalid](CaseKind::Invalid) instance.
     pub fn is_invalid(&self) -> bool {
-        matches!(self, NamingCase::Invalid(_))
+        self.kind == CaseKind::Invalid
+    }
+
+    /// Get the words of the included string, as they are written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use naming_lib::from;
+    ///
+    /// assert_eq!(vec!["user", "Id"], from("userId").words().unwrap());
+    /// assert_eq!(vec!["USER", "ID"], from("USER_ID").words().unwrap());
+    /// ```
+    /// # Errors
+    ///
+    /// Perform this on an [Invalid](CaseKind::Invalid) instance
+    /// will get an [Err](core::result::Result::Err).
+    pub fn words(&self) -> Result<Vec<String>, &'static str> {
+        extract_words_from(self)
+    }
+
+    /// Get the words as written, guessed like
+    /// [to_case_lossy()](NamingCase::to_case_lossy()) does
+    /// if this is an [Invalid](CaseKind::Invalid) instance.
+    pub(crate) fn words_lossy(&self) -> Vec<String> {
+        match self.kind {
+            CaseKind::Invalid => extract_words_lossy(&self.value),
+            _ => extract_words_from(self).unwrap(),
+        }
     }
 
     /// Convert the included string to screaming snake case.
@@ -148,15 +364,11 @@ impl NamingCase {
     /// ```
     /// # Errors
     ///
-    /// Perform this on [Invalid](NamingCase::Invalid) enum
+    /// Perform this on an [Invalid](CaseKind::Invalid) instance
     /// will get an [Err](core::result::Result::Err).
     pub fn to_screaming_snake(&self) -> Result<String, &'static str> {
         let words = extract_words_from(self)?;
-        Ok(words
-            .into_iter()
-            .map(|word| word.to_ascii_uppercase())
-            .collect::<Vec<String>>()
-            .join("_"))
+        Ok(compose_words(words, TargetCase::ScreamingSnake))
     }
 
     /// Convert the included string to snake case.
@@ -172,15 +384,11 @@ impl NamingCase {
     /// ```
     /// # Errors
     ///
-    /// Perform this on [Invalid](NamingCase::Invalid) enum
+    /// Perform this on an [Invalid](CaseKind::Invalid) instance
     /// will get an [Err](core::result::Result::Err).
     pub fn to_snake(&self) -> Result<String, &'static str> {
         let words = extract_words_from(self)?;
-        Ok(words
-            .into_iter()
-            .map(|word| word.to_ascii_lowercase())
-            .collect::<Vec<String>>()
-            .join("_"))
+        Ok(compose_words(words, TargetCase::Snake))
     }
 
     /// Convert the included string to kebab case.
@@ -196,15 +404,11 @@ impl NamingCase {
     /// ```
     /// # Errors
     ///
-    /// Perform this on [Invalid](NamingCase::Invalid) enum
+    /// Perform this on an [Invalid](CaseKind::Invalid) instance
     /// will get an [Err](core::result::Result::Err).
     pub fn to_kebab(&self) -> Result<String, &'static str> {
         let words = extract_words_from(self)?;
-        Ok(words
-            .into_iter()
-            .map(|word| word.to_ascii_lowercase())
-            .collect::<Vec<String>>()
-            .join("-"))
+        Ok(compose_words(words, TargetCase::Kebab))
     }
 
     /// Convert the included string to camel case.
@@ -220,15 +424,12 @@ impl NamingCase {
     /// ```
     /// # Errors
     ///
-    /// Perform this on [Invalid](NamingCase::Invalid) enum
+    /// Perform this on an [Invalid](CaseKind::Invalid) instance
     /// will get an [Err](core::result::Result::Err).
     ///
     pub fn to_camel(&self) -> Result<String, &'static str> {
         let words = extract_words_from(self)?;
-        let mut iter = words.into_iter();
-        let first_word = iter.next().unwrap();
-        Ok(first_word.to_ascii_lowercase()
-            + &compose_words_to_pascal(iter.collect()))
+        Ok(compose_words(words, TargetCase::Camel))
     }
 
     /// Convert the included string to pascal case.
@@ -244,93 +445,298 @@ impl NamingCase {
     /// ```
     /// # Errors
     ///
-    /// Perform this on [Invalid](NamingCase::Invalid) enum
+    /// Perform this on an [Invalid](CaseKind::Invalid) instance
     /// will get an [Err](core::result::Result::Err).
     pub fn to_pascal(&self) -> Result<String, &'static str> {
         let words = extract_words_from(self)?;
-        Ok(compose_words_to_pascal(words))
+        Ok(compose_words(words, TargetCase::Pascal))
+    }
+
+    /// Convert the included string to the given target case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use naming_lib::{from, TargetCase};
+    ///
+    /// assert_eq!("CAMEL_CASE", from("camelCase").to_case(TargetCase::ScreamingSnake).unwrap());
+    /// assert_eq!("SnakeCase", from("snake_case").to_case(TargetCase::Pascal).unwrap());
+    /// ```
+    /// # Errors
+    ///
+    /// Perform this on an [Invalid](CaseKind::Invalid) instance
+    /// will get an [Err](core::result::Result::Err).
+    pub fn to_case(&self, target: TargetCase) -> Result<String, &'static str> {
+        let words = extract_words_from(self)?;
+        Ok(compose_words(words, target))
+    }
+
+    /// Convert the included string to the given target case,
+    /// then escape it if it is a keyword of the language,
+    /// see [escape()](crate::language::Language::escape()).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use naming_lib::language::Language;
+    /// use naming_lib::{from, TargetCase};
+    ///
+    /// assert_eq!("r#type", from("Type").to_case_escaped(TargetCase::Snake, Language::Rust).unwrap());
+    /// assert_eq!("userType", from("UserType").to_case_escaped(TargetCase::Camel, Language::Rust).unwrap());
+    /// ```
+    /// # Errors
+    ///
+    /// Perform this on an [Invalid](CaseKind::Invalid) instance
+    /// will get an [Err](core::result::Result::Err).
+    pub fn to_case_escaped(
+        &self,
+        target: TargetCase,
+        language: Language,
+    ) -> Result<String, &'static str> {
+        let converted = self.to_case(target)?;
+        if language.is_keyword(&converted) {
+            Ok(language.escape(&converted).into_owned())
+        } else {
+            Ok(converted)
+        }
+    }
+
+    /// Convert the included string to hungarian notation,
+    /// with the Systems Hungarian prefix of the given type
+    /// followed by the words in pascal case, so it's in camel case as a whole.
+    ///
+    /// Same as [to_hungarian_with()](NamingCase::to_hungarian_with())
+    /// on the [default()](crate::hungarian::PrefixDictionary::default())
+    /// dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use naming_lib::from;
+    ///
+    /// assert_eq!("szFileName", from("file_name").to_hungarian("char*").unwrap());
+    /// assert_eq!("dwFlags", from("FLAGS").to_hungarian("DWORD").unwrap());
+    /// assert!(from("file_name").to_hungarian("Widget").is_err());
+    /// ```
+    /// # Errors
+    ///
+    /// Get an [Err](core::result::Result::Err)
+    /// if this is an [Invalid](CaseKind::Invalid) instance,
+    /// or no prefix is known for the type.
+    pub fn to_hungarian(
+        &self,
+        type_hint: &str,
+    ) -> Result<String, &'static str> {
+        self.to_hungarian_with(type_hint, &PrefixDictionary::default())
+    }
+
+    /// Convert the included string to hungarian notation,
+    /// with the prefix of the given type found in the dictionary,
+    /// see [prefix_for_type()](PrefixDictionary::prefix_for_type()).
+    ///
+    /// # Errors
+    ///
+    /// Get an [Err](core::result::Result::Err)
+    /// if this is an [Invalid](CaseKind::Invalid) instance,
+    /// or no prefix is known for the type.
+    pub fn to_hungarian_with(
+        &self,
+        type_hint: &str,
+        dictionary: &PrefixDictionary,
+    ) -> Result<String, &'static str> {
+        let words = self.to_pascal()?;
+        let prefix = dictionary
+            .prefix_for_type(type_hint)
+            .ok_or("No prefix is known for this type.")?;
+        Ok(format!("{}{}", prefix.prefix, words))
+    }
+
+    /// Convert the included string to the given target case,
+    /// even if it is an [Invalid](CaseKind::Invalid) instance.
+    ///
+    /// Words of an invalid identifier are guessed by splitting it
+    /// on every non-alphanumeric character and on case changes,
+    /// characters out of ASCII are dropped.
+    /// The result may still be an invalid identifier,
+    /// for example when the input starts with digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use naming_lib::{from, TargetCase};
+    ///
+    /// assert_eq!("userId", from("user id").to_case_lossy(TargetCase::Camel).unwrap());
+    /// assert_eq!("FOO_BAR", from("@foo.bar").to_case_lossy(TargetCase::ScreamingSnake).unwrap());
+    /// assert_eq!("user_id", from("user_ID").to_case_lossy(TargetCase::Snake).unwrap());
+    /// ```
+    /// # Errors
+    ///
+    /// Get an [Err](core::result::Result::Err)
+    /// if there isn't any word in the included string.
+    pub fn to_case_lossy(
+        &self,
+        target: TargetCase,
+    ) -> Result<String, &'static str> {
+        let words = self.words_lossy();
+        if words.is_empty() {
+            return Err("Can't find any word in this identifier.");
+        }
+        Ok(compose_words(words, target))
+    }
+
+    /// Get a key that is the same for identifiers made of the same words,
+    /// regardless of their formats:
+    /// lowercase words joined by [NORMALIZATION_SEPARATOR].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use naming_lib::from;
+    ///
+    /// assert_eq!("user.id", from("userId").normalization_key().unwrap());
+    /// assert_eq!("user.id", from("USER_ID").normalization_key().unwrap());
+    /// ```
+    /// # Errors
+    ///
+    /// Perform this on an [Invalid](CaseKind::Invalid) instance
+    /// will get an [Err](core::result::Result::Err).
+    pub fn normalization_key(&self) -> Result<String, &'static str> {
+        let words = extract_words_from(self)?;
+        Ok(words
+            .into_iter()
+            .map(|word| word.to_ascii_lowercase())
+            .collect::<Vec<String>>()
+            .join(&NORMALIZATION_SEPARATOR.to_string()))
+    }
+
+    /// Check if two identifiers are made of the same words,
+    /// regardless of their formats.
+    ///
+    /// [Invalid](CaseKind::Invalid) instances have no words,
+    /// they only have the same words as an identical string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use naming_lib::from;
+    ///
+    /// assert!(from("userId").same_words(&from("user-id")));
+    /// assert!(!from("userId").same_words(&from("user_name")));
+    /// ```
+    pub fn same_words(&self, other: &NamingCase<'_>) -> bool {
+        match (self.normalization_key(), other.normalization_key()) {
+            (Ok(key), Ok(other_key)) => key == other_key,
+            (Err(_), Err(_)) => self.value == other.value,
+            _ => false,
+        }
     }
 }
 
+/// Joins words in a [normalization key](NamingCase::normalization_key()),
+/// it never appears in a word of a valid identifier.
+pub const NORMALIZATION_SEPARATOR: char = '.';
+
 /// Create a [NamingCase] value from an identifier.
 ///
 /// Alias of [which_case()](crate::detector::which_case()) and [NamingCase::new()].
-pub fn from(identifier: &str) -> NamingCase {
+pub fn from(identifier: &str) -> NamingCase<'_> {
     detector::which_case(identifier)
 }
 
-/// Return a [Pascal](NamingCase::Pascal) enum for a hungarian notation identifier,
+/// Return a [Pascal](CaseKind::Pascal) instance for a hungarian notation identifier,
 /// remove the first word which representing the variable type.
+/// It's a [SingleWord](CaseKind::SingleWord) instance instead
+/// if only one word remains, like `Size` in `iSize`.
 ///
-/// Or return a [Invalid](NamingCase::Invalid) enum for other inputs.
+/// The first word is dropped whatever it is,
+/// use [strip_prefix()](crate::hungarian::strip_prefix())
+/// to strip known prefixes only.
 ///
 /// # Examples
 ///
 /// ```
-/// use naming_lib::{from_hungarian_notation,NamingCase};
+/// use naming_lib::{from_hungarian_notation, CaseKind};
 ///
-/// let valid = from_hungarian_notation("iPageSize");
-/// assert_eq!(valid, NamingCase::Pascal("PageSize".to_string()));
+/// let valid = from_hungarian_notation("iPageSize").unwrap();
+/// assert_eq!(valid.kind(), CaseKind::Pascal);
 /// assert_eq!(valid.to_string(), "PageSize");
 ///
+/// let single = from_hungarian_notation("iSize").unwrap();
+/// assert_eq!(single.kind(), CaseKind::SingleWord);
+/// assert_eq!(single.to_string(), "Size");
+///
 /// // A hungarian notation identifier will be recognized as a camel case.
-/// // Even though this is a valid pascal case, it will still be treated as invalid.
-/// let invalid = from_hungarian_notation("NotACamelCase");
-/// assert_eq!(invalid, NamingCase::Invalid("NotACamelCase".to_string()));
+/// // Even though this is a valid pascal case, it isn't a hungarian notation.
+/// assert!(from_hungarian_notation("NotACamelCase").is_err());
 /// ```
-pub fn from_hungarian_notation(identifier: &str) -> NamingCase {
+/// # Errors
+///
+/// Get an [Err](core::result::Result::Err)
+/// if the identifier isn't in camel case.
+pub fn from_hungarian_notation(
+    identifier: &str,
+) -> Result<NamingCase<'static>, &'static str> {
     let real_case = detector::which_case(identifier);
-    if real_case != NamingCase::Camel(identifier.to_string()) {
-        return NamingCase::Invalid(identifier.to_string());
+    if real_case.kind != CaseKind::Camel {
+        return Err("Not a hungarian notation identifier.");
     }
 
     let mut iter = extract_words_from(&real_case).unwrap().into_iter();
     // discard first word
     iter.next();
-    // return remains as a pascal case.
-    NamingCase::Pascal(iter.collect::<Vec<String>>().join(""))
+    // Detect the remains again, one remaining word is a single word.
+    let remains = iter.collect::<Vec<String>>().join("");
+    Ok(detector::which_case(&remains).into_owned())
 }
 
 lazy_static! {
-    static ref LOWER_CASE_REGEX: Regex = Regex::new(r"^[a-z]+\d*").unwrap();
     static ref FIRST_UPPER_CASE_REGEX: Regex =
         Regex::new(r"[A-Z][a-z]*\d*").unwrap();
+    static ref LOSSY_WORD_REGEX: Regex =
+        Regex::new(r"[A-Z]+[a-z]+\d*|[A-Z]+\d*|[a-z]+\d*|\d+").unwrap();
 }
 
 fn extract_words_from(case: &NamingCase) -> Result<Vec<String>, &'static str> {
-    return match case {
-        NamingCase::SingleWord(ori) => Ok(vec![ori.to_string()]),
-        NamingCase::ScreamingSnake(ori) => {
-            Ok(ori.split('_').map(|word| word.to_string()).collect())
-        }
-        NamingCase::Snake(ori) => {
+    let ori = &case.value;
+    match case.kind {
+        CaseKind::SingleWord => Ok(vec![ori.to_string()]),
+        CaseKind::ScreamingSnake | CaseKind::Snake => {
             Ok(ori.split('_').map(|word| word.to_string()).collect())
         }
-        NamingCase::Kebab(ori) => {
+        CaseKind::Kebab => {
             Ok(ori.split('-').map(|word| word.to_string()).collect())
         }
-        NamingCase::Camel(ori) => {
-            let mut words = Vec::new();
-
-            let first_word = LOWER_CASE_REGEX
-                .captures(ori)
-                .unwrap()
-                .get(0)
-                .unwrap()
-                .as_str()
-                .to_string();
-
-            let other_words = ori.strip_prefix(&first_word).unwrap();
-            let mut other_words = extract_words_from_pascal(other_words);
-
-            words.push(first_word.to_ascii_lowercase());
-            words.append(&mut other_words);
+        CaseKind::Camel => {
+            // The first word is the lowercase part before the first uppercase.
+            let first_word_end =
+                ori.find(|c: char| c.is_ascii_uppercase()).unwrap_or(ori.len());
+            let (first_word, other_words) = ori.split_at(first_word_end);
 
+            let mut words = vec![first_word.to_string()];
+            words.append(&mut extract_words_from_pascal(other_words));
             Ok(words)
         }
-        NamingCase::Pascal(ori) => Ok(extract_words_from_pascal(ori)),
-        NamingCase::Invalid(_) => Err("Can't extract words from this type."),
-    };
+        CaseKind::Pascal => Ok(extract_words_from_pascal(ori)),
+        CaseKind::Invalid => Err("Can't extract words from this type."),
+    }
+}
+
+/// Split an invalid identifier into alphanumeric chunks,
+/// use the words of a chunk if it is a known format,
+/// otherwise split it on case changes.
+fn extract_words_lossy(s: &str) -> Vec<String> {
+    s.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|chunk| !chunk.is_empty())
+        .flat_map(|chunk| {
+            match extract_words_from(&detector::which_case(chunk)) {
+                Ok(words) => words,
+                Err(_) => LOSSY_WORD_REGEX
+                    .find_iter(chunk)
+                    .map(|mat| mat.as_str().to_string())
+                    .collect(),
+            }
+        })
+        .collect()
 }
 
 fn extract_words_from_pascal(s: &str) -> Vec<String> {
@@ -340,6 +746,33 @@ fn extract_words_from_pascal(s: &str) -> Vec<String> {
         .collect()
 }
 
+pub(crate) fn compose_words(words: Vec<String>, target: TargetCase) -> String {
+    match target {
+        TargetCase::ScreamingSnake => words
+            .into_iter()
+            .map(|word| word.to_ascii_uppercase())
+            .collect::<Vec<String>>()
+            .join("_"),
+        TargetCase::Snake => words
+            .into_iter()
+            .map(|word| word.to_ascii_lowercase())
+            .collect::<Vec<String>>()
+            .join("_"),
+        TargetCase::Kebab => words
+            .into_iter()
+            .map(|word| word.to_ascii_lowercase())
+            .collect::<Vec<String>>()
+            .join("-"),
+        TargetCase::Camel => {
+            let mut iter = words.into_iter();
+            let first_word = iter.next().unwrap();
+            first_word.to_ascii_lowercase()
+                + &compose_words_to_pascal(iter.collect())
+        }
+        TargetCase::Pascal => compose_words_to_pascal(words),
+    }
+}
+
 fn compose_words_to_pascal(words: Vec<String>) -> String {
     words.into_iter().map(to_first_uppercase).collect::<Vec<String>>().join("")
 }
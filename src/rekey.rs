@@ -0,0 +1,283 @@
+//! Convert every object key of a parsed document tree to a target case.
+//!
+//! Available for [serde_json::Value] with the `serde_json` feature
+//! and for [toml::Value] with the `toml` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "serde_json")]
+//! # {
+//! use naming_lib::rekey::{rekey_json, RekeyOptions};
+//! use naming_lib::TargetCase;
+//! use serde_json::json;
+//!
+//! let mut payload = json!({"user_id": 1, "home_address": {"street_name": "x"}});
+//! rekey_json(&mut payload, &RekeyOptions::new(TargetCase::Camel)).unwrap();
+//!
+//! assert_eq!(payload, json!({"userId": 1, "homeAddress": {"streetName": "x"}}));
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{detector, TargetCase};
+
+/// What to do with keys which are recognized as
+/// [Invalid](crate::CaseKind::Invalid).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum InvalidKeys {
+    /// Keep them as they are.
+    Untouched,
+    /// Convert them with [to_case_lossy()](crate::NamingCase::to_case_lossy()),
+    /// keys without any word are still kept as they are.
+    Lossy,
+}
+
+/// What to do when two keys of one object are converted to the same key.
+///
+/// "First" and "last" follow the order in which the map yields its keys.
+/// Maps of [serde_json] and [toml] are sorted by key unless
+/// their `preserve_order` feature is enabled,
+/// in which case it's the document order.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OnCollision {
+    /// Keep the value of the key that comes first.
+    KeepFirst,
+    /// Keep the value of the key that comes last.
+    KeepLast,
+    /// Stop converting and return a [KeyCollision] error.
+    Error,
+}
+
+/// Options of a key conversion.
+///
+/// Create one with [RekeyOptions::new()] and change its fields as needed.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RekeyOptions {
+    /// The case that keys are converted to.
+    pub target: TargetCase,
+    /// How many levels of nested objects are converted,
+    /// the root object is the first level. Arrays don't count as a level.
+    ///
+    /// `None` for no limit, which is the default.
+    pub max_depth: Option<usize>,
+    /// Keys that are kept as they are.
+    /// Values of these keys are not walked into either.
+    pub exclude: Vec<String>,
+    /// Default is [KeepLast](OnCollision::KeepLast).
+    pub on_collision: OnCollision,
+    /// Default is [Untouched](InvalidKeys::Untouched).
+    pub invalid_keys: InvalidKeys,
+}
+
+impl RekeyOptions {
+    /// Convert all keys to the target case, without any limitation.
+    pub fn new(target: TargetCase) -> Self {
+        RekeyOptions {
+            target,
+            max_depth: None,
+            exclude: Vec::new(),
+            on_collision: OnCollision::KeepLast,
+            invalid_keys: InvalidKeys::Untouched,
+        }
+    }
+
+    fn is_excluded(&self, key: &str) -> bool {
+        self.exclude.iter().any(|excluded| excluded == key)
+    }
+
+    fn convert(&self, key: &str) -> Option<String> {
+        let case = detector::which_case(key);
+        let converted = match self.invalid_keys {
+            InvalidKeys::Untouched => case.to_case(self.target),
+            InvalidKeys::Lossy => case.to_case_lossy(self.target),
+        };
+        converted.ok()
+    }
+}
+
+/// Two keys of one object became the same key after conversion,
+/// returned when [OnCollision::Error] is chosen.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct KeyCollision {
+    /// Keys from the root to the object containing colliding keys,
+    /// in their original form.
+    pub path: Vec<String>,
+    /// Both original keys, in the order they appear.
+    pub keys: (String, String),
+    /// The key both are converted to.
+    pub converted: String,
+}
+
+impl Display for KeyCollision {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "keys \"{}\" and \"{}\" at \"/{}\" are both converted to \"{}\"",
+            self.keys.0,
+            self.keys.1,
+            self.path.join("/"),
+            self.converted
+        )
+    }
+}
+
+impl Error for KeyCollision {}
+
+/// Convert keys of one object,
+/// then walk into values with `walk_value`.
+fn rekey_entries<V>(
+    entries: Vec<(String, V)>,
+    options: &RekeyOptions,
+    path: &mut Vec<String>,
+    walk_value: fn(
+        &mut V,
+        &RekeyOptions,
+        &mut Vec<String>,
+    ) -> Result<(), KeyCollision>,
+) -> Result<Vec<(String, V)>, KeyCollision> {
+    let depth = path.len() + 1;
+    if options.max_depth.is_some_and(|max| depth > max) {
+        return Ok(entries);
+    }
+
+    let mut converted: Vec<(String, V)> = Vec::with_capacity(entries.len());
+    // converted key -> (index in `converted`, original key)
+    let mut seen: HashMap<String, (usize, String)> = HashMap::new();
+
+    for (key, mut value) in entries {
+        // Values under keys that can't be converted are still walked,
+        // only excluded keys stop the walk.
+        let new_key = if options.is_excluded(&key) {
+            key.clone()
+        } else {
+            path.push(key.clone());
+            let walked = walk_value(&mut value, options, path);
+            path.pop();
+            walked?;
+            options.convert(&key).unwrap_or_else(|| key.clone())
+        };
+
+        match seen.get(&new_key) {
+            None => {
+                seen.insert(new_key.clone(), (converted.len(), key));
+                converted.push((new_key, value));
+            }
+            Some((index, first)) => match options.on_collision {
+                OnCollision::KeepFirst => {}
+                OnCollision::KeepLast => converted[*index].1 = value,
+                OnCollision::Error => {
+                    return Err(KeyCollision {
+                        path: path.clone(),
+                        keys: (first.clone(), key),
+                        converted: new_key,
+                    })
+                }
+            },
+        }
+    }
+
+    Ok(converted)
+}
+
+/// Restore the value if conversion stops at a collision,
+/// it's only possible with [OnCollision::Error].
+fn with_backup<V: Clone>(
+    value: &mut V,
+    options: &RekeyOptions,
+    walk: fn(
+        &mut V,
+        &RekeyOptions,
+        &mut Vec<String>,
+    ) -> Result<(), KeyCollision>,
+) -> Result<(), KeyCollision> {
+    let backup = match options.on_collision {
+        OnCollision::Error => Some(value.clone()),
+        _ => None,
+    };
+
+    let result = walk(value, options, &mut Vec::new());
+    if let (Err(_), Some(backup)) = (&result, backup) {
+        *value = backup;
+    }
+    result
+}
+
+/// Convert every object key in a JSON value to the target case.
+///
+/// # Errors
+///
+/// Get a [KeyCollision] if two keys of one object collide
+/// and [OnCollision::Error] is chosen,
+/// the value is left unchanged in this case.
+#[cfg(feature = "serde_json")]
+pub fn rekey_json(
+    value: &mut serde_json::Value,
+    options: &RekeyOptions,
+) -> Result<(), KeyCollision> {
+    with_backup(value, options, walk_json)
+}
+
+#[cfg(feature = "serde_json")]
+fn walk_json(
+    value: &mut serde_json::Value,
+    options: &RekeyOptions,
+    path: &mut Vec<String>,
+) -> Result<(), KeyCollision> {
+    match value {
+        serde_json::Value::Object(map) => {
+            let entries = std::mem::take(map).into_iter().collect();
+            *map = rekey_entries(entries, options, path, walk_json)?
+                .into_iter()
+                .collect();
+        }
+        serde_json::Value::Array(values) => {
+            for value in values {
+                walk_json(value, options, path)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Convert every table key in a TOML value to the target case.
+///
+/// # Errors
+///
+/// Get a [KeyCollision] if two keys of one table collide
+/// and [OnCollision::Error] is chosen,
+/// the value is left unchanged in this case.
+#[cfg(feature = "toml")]
+pub fn rekey_toml(
+    value: &mut toml::Value,
+    options: &RekeyOptions,
+) -> Result<(), KeyCollision> {
+    with_backup(value, options, walk_toml)
+}
+
+#[cfg(feature = "toml")]
+fn walk_toml(
+    value: &mut toml::Value,
+    options: &RekeyOptions,
+    path: &mut Vec<String>,
+) -> Result<(), KeyCollision> {
+    match value {
+        toml::Value::Table(table) => {
+            let entries = std::mem::take(table).into_iter().collect();
+            *table = rekey_entries(entries, options, path, walk_toml)?
+                .into_iter()
+                .collect();
+        }
+        toml::Value::Array(values) => {
+            for value in values {
+                walk_toml(value, options, path)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
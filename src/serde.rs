@@ -0,0 +1,807 @@
+//! Adapters that rename map keys and struct field names while (de)serializing.
+//!
+//! serde's `rename_all` attribute is decided at compile time.
+//! The [Serializer] and [Deserializer] here wrap any other serde
+//! (de)serializer and rewrite keys into a [TargetCase] chosen at runtime,
+//! so one set of structs can talk to formats with different key conventions.
+//!
+//! Only keys are touched, string values pass through unchanged.
+//! Keys that are recognized as [Invalid](crate::CaseKind::Invalid)
+//! are also left as they are.
+//!
+//! # Examples
+//!
+//! ```
+//! use naming_lib::{serde::Serializer, TargetCase};
+//! use serde::Serialize;
+//!
+//! #[derive(Serialize)]
+//! struct User {
+//!     user_id: u32,
+//!     display_name: String,
+//! }
+//!
+//! let user = User { user_id: 1, display_name: "snake_case".to_string() };
+//!
+//! let mut out = Vec::new();
+//! let mut json = serde_json::Serializer::new(&mut out);
+//! user.serialize(Serializer::new(&mut json, TargetCase::Camel)).unwrap();
+//!
+//! assert_eq!(
+//!     r#"{"userId":1,"displayName":"snake_case"}"#,
+//!     String::from_utf8(out).unwrap()
+//! );
+//! ```
+//!
+//! ```
+//! use naming_lib::{serde::Deserializer, TargetCase};
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct User {
+//!     user_id: u32,
+//! }
+//!
+//! let mut json = serde_json::Deserializer::from_str(r#"{"userId":1}"#);
+//! let user = User::deserialize(Deserializer::new(&mut json, TargetCase::Snake)).unwrap();
+//!
+//! assert_eq!(1, user.user_id);
+//! ```
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::sync::Mutex;
+
+use ::serde::de::{self, DeserializeSeed};
+use ::serde::ser::{self, Serialize};
+use lazy_static::lazy_static;
+
+use crate::{detector, TargetCase};
+
+/// Convert a key to the target case,
+/// borrow it back if nothing needs to be changed.
+fn rename(key: &str, target: TargetCase) -> Cow<'_, str> {
+    match detector::which_case(key).to_case(target) {
+        Ok(renamed) if renamed != key => Cow::Owned(renamed),
+        _ => Cow::Borrowed(key),
+    }
+}
+
+/// Struct field names must be `&'static str`,
+/// so renamed names are leaked once and reused afterwards.
+/// Field names are finite, so is the leaked memory.
+fn rename_field(field: &'static str, target: TargetCase) -> &'static str {
+    lazy_static! {
+        static ref RENAMED_FIELDS: Mutex<HashMap<(&'static str, TargetCase), &'static str>> =
+            Mutex::new(HashMap::new());
+    }
+
+    match rename(field, target) {
+        Cow::Borrowed(_) => field,
+        Cow::Owned(renamed) => {
+            let mut renamed_fields = RENAMED_FIELDS.lock().unwrap();
+            renamed_fields
+                .entry((field, target))
+                .or_insert_with(|| Box::leak(renamed.into_boxed_str()))
+        }
+    }
+}
+
+/// A serializer wrapper that renames every map key and struct field name
+/// into the target case before passing them to the wrapped serializer.
+pub struct Serializer<S> {
+    inner: S,
+    target: TargetCase,
+    // Whether the value being serialized is a map key.
+    is_key: bool,
+}
+
+impl<S> Serializer<S> {
+    /// Wrap a serializer, renaming keys into the given case.
+    pub fn new(inner: S, target: TargetCase) -> Self {
+        Serializer { inner, target, is_key: false }
+    }
+}
+
+/// Serializes a nested value through a [Serializer] with the same target.
+struct Value<'a, T: ?Sized> {
+    value: &'a T,
+    target: TargetCase,
+    is_key: bool,
+}
+
+impl<'a, T: ?Sized> Value<'a, T> {
+    fn new(value: &'a T, target: TargetCase) -> Self {
+        Value { value, target, is_key: false }
+    }
+
+    fn key(value: &'a T, target: TargetCase) -> Self {
+        Value { value, target, is_key: true }
+    }
+}
+
+impl<T: ?Sized + Serialize> Serialize for Value<'_, T> {
+    fn serialize<S: ser::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let wrapped = Serializer {
+            inner: serializer,
+            target: self.target,
+            is_key: self.is_key,
+        };
+        self.value.serialize(wrapped)
+    }
+}
+
+/// Wraps the compound serializers returned by the inner serializer.
+pub struct Compound<C> {
+    inner: C,
+    target: TargetCase,
+}
+
+macro_rules! forward_serialize {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, v: $ty) -> Result<Self::Ok, Self::Error> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<S: ser::Serializer> ser::Serializer for Serializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Compound<S::SerializeSeq>;
+    type SerializeTuple = Compound<S::SerializeTuple>;
+    type SerializeTupleStruct = Compound<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Compound<S::SerializeTupleVariant>;
+    type SerializeMap = Compound<S::SerializeMap>;
+    type SerializeStruct = Compound<S::SerializeStruct>;
+    type SerializeStructVariant = Compound<S::SerializeStructVariant>;
+
+    forward_serialize! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_bytes(&[u8]),
+        serialize_unit_struct(&'static str),
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        if self.is_key {
+            self.inner.serialize_str(&rename(v, self.target))
+        } else {
+            self.inner.serialize_str(v)
+        }
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_none()
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(
+        self,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_some(&Value::new(value, self.target))
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        let value = Value { value, target: self.target, is_key: self.is_key };
+        self.inner.serialize_newtype_struct(name, &value)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_newtype_variant(
+            name,
+            variant_index,
+            variant,
+            &Value::new(value, self.target),
+        )
+    }
+
+    fn serialize_seq(
+        self,
+        len: Option<usize>,
+    ) -> Result<Self::SerializeSeq, Self::Error> {
+        let inner = self.inner.serialize_seq(len)?;
+        Ok(Compound { inner, target: self.target })
+    }
+
+    fn serialize_tuple(
+        self,
+        len: usize,
+    ) -> Result<Self::SerializeTuple, Self::Error> {
+        let inner = self.inner.serialize_tuple(len)?;
+        Ok(Compound { inner, target: self.target })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        let inner = self.inner.serialize_tuple_struct(name, len)?;
+        Ok(Compound { inner, target: self.target })
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        let inner = self.inner.serialize_tuple_variant(
+            name,
+            variant_index,
+            variant,
+            len,
+        )?;
+        Ok(Compound { inner, target: self.target })
+    }
+
+    fn serialize_map(
+        self,
+        len: Option<usize>,
+    ) -> Result<Self::SerializeMap, Self::Error> {
+        let inner = self.inner.serialize_map(len)?;
+        Ok(Compound { inner, target: self.target })
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        let inner = self.inner.serialize_struct(name, len)?;
+        Ok(Compound { inner, target: self.target })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        let inner = self.inner.serialize_struct_variant(
+            name,
+            variant_index,
+            variant,
+            len,
+        )?;
+        Ok(Compound { inner, target: self.target })
+    }
+
+    fn collect_str<T: ?Sized + Display>(
+        self,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        if self.is_key {
+            self.serialize_str(&value.to_string())
+        } else {
+            self.inner.collect_str(value)
+        }
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! impl_serialize_elements {
+    ($($trait:ident::$method:ident),* $(,)?) => {
+        $(
+            impl<C: ser::$trait> ser::$trait for Compound<C> {
+                type Ok = C::Ok;
+                type Error = C::Error;
+
+                fn $method<T: ?Sized + Serialize>(
+                    &mut self,
+                    value: &T,
+                ) -> Result<(), Self::Error> {
+                    self.inner.$method(&Value::new(value, self.target))
+                }
+
+                fn end(self) -> Result<Self::Ok, Self::Error> {
+                    self.inner.end()
+                }
+            }
+        )*
+    };
+}
+
+impl_serialize_elements! {
+    SerializeSeq::serialize_element,
+    SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field,
+    SerializeTupleVariant::serialize_field,
+}
+
+macro_rules! impl_serialize_fields {
+    ($($trait:ident),* $(,)?) => {
+        $(
+            impl<C: ser::$trait> ser::$trait for Compound<C> {
+                type Ok = C::Ok;
+                type Error = C::Error;
+
+                fn serialize_field<T: ?Sized + Serialize>(
+                    &mut self,
+                    key: &'static str,
+                    value: &T,
+                ) -> Result<(), Self::Error> {
+                    self.inner.serialize_field(
+                        rename_field(key, self.target),
+                        &Value::new(value, self.target),
+                    )
+                }
+
+                fn skip_field(
+                    &mut self,
+                    key: &'static str,
+                ) -> Result<(), Self::Error> {
+                    self.inner.skip_field(rename_field(key, self.target))
+                }
+
+                fn end(self) -> Result<Self::Ok, Self::Error> {
+                    self.inner.end()
+                }
+            }
+        )*
+    };
+}
+
+impl_serialize_fields! {
+    SerializeStruct,
+    SerializeStructVariant,
+}
+
+impl<C: ser::SerializeMap> ser::SerializeMap for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(
+        &mut self,
+        key: &T,
+    ) -> Result<(), Self::Error> {
+        self.inner.serialize_key(&Value::key(key, self.target))
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.inner.serialize_value(&Value::new(value, self.target))
+    }
+
+    fn serialize_entry<K: ?Sized + Serialize, V: ?Sized + Serialize>(
+        &mut self,
+        key: &K,
+        value: &V,
+    ) -> Result<(), Self::Error> {
+        self.inner.serialize_entry(
+            &Value::key(key, self.target),
+            &Value::new(value, self.target),
+        )
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+/// A deserializer wrapper that renames every map key
+/// into the target case before handing them to the visitor.
+///
+/// Struct field names are map keys in self-describing formats,
+/// so a struct with snake case fields can be read from camel case input
+/// by choosing [Snake](TargetCase::Snake) as the target.
+pub struct Deserializer<D> {
+    inner: D,
+    target: TargetCase,
+    is_key: bool,
+}
+
+impl<D> Deserializer<D> {
+    /// Wrap a deserializer, renaming keys into the given case.
+    pub fn new(inner: D, target: TargetCase) -> Self {
+        Deserializer { inner, target, is_key: false }
+    }
+}
+
+/// Wraps the visitor given by the deserialized type,
+/// so that nested maps are visited through the wrappers too.
+struct Visitor<V> {
+    delegate: V,
+    target: TargetCase,
+    is_key: bool,
+}
+
+impl<V> Visitor<V> {
+    fn new(delegate: V, target: TargetCase, is_key: bool) -> Self {
+        Visitor { delegate, target, is_key }
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident),* $(,)?) => {
+        $(
+            fn $method<V: de::Visitor<'de>>(
+                self,
+                visitor: V,
+            ) -> Result<V::Value, Self::Error> {
+                self.inner.$method(Visitor::new(visitor, self.target, self.is_key))
+            }
+        )*
+    };
+}
+
+impl<'de, D: de::Deserializer<'de>> de::Deserializer<'de> for Deserializer<D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any,
+        deserialize_bool,
+        deserialize_i8,
+        deserialize_i16,
+        deserialize_i32,
+        deserialize_i64,
+        deserialize_i128,
+        deserialize_u8,
+        deserialize_u16,
+        deserialize_u32,
+        deserialize_u64,
+        deserialize_u128,
+        deserialize_f32,
+        deserialize_f64,
+        deserialize_char,
+        deserialize_str,
+        deserialize_string,
+        deserialize_bytes,
+        deserialize_byte_buf,
+        deserialize_option,
+        deserialize_unit,
+        deserialize_seq,
+        deserialize_map,
+        deserialize_identifier,
+        deserialize_ignored_any,
+    }
+
+    fn deserialize_unit_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let visitor = Visitor::new(visitor, self.target, self.is_key);
+        self.inner.deserialize_unit_struct(name, visitor)
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let visitor = Visitor::new(visitor, self.target, self.is_key);
+        self.inner.deserialize_newtype_struct(name, visitor)
+    }
+
+    fn deserialize_tuple<V: de::Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let visitor = Visitor::new(visitor, self.target, self.is_key);
+        self.inner.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_tuple_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let visitor = Visitor::new(visitor, self.target, self.is_key);
+        self.inner.deserialize_tuple_struct(name, len, visitor)
+    }
+
+    fn deserialize_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let visitor = Visitor::new(visitor, self.target, self.is_key);
+        self.inner.deserialize_struct(name, fields, visitor)
+    }
+
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let visitor = Visitor::new(visitor, self.target, self.is_key);
+        self.inner.deserialize_enum(name, variants, visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<E: de::Error>(self, v: $ty) -> Result<Self::Value, E> {
+                self.delegate.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V: de::Visitor<'de>> de::Visitor<'de> for Visitor<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        self.delegate.expecting(formatter)
+    }
+
+    forward_visit! {
+        visit_bool(bool),
+        visit_i8(i8),
+        visit_i16(i16),
+        visit_i32(i32),
+        visit_i64(i64),
+        visit_i128(i128),
+        visit_u8(u8),
+        visit_u16(u16),
+        visit_u32(u32),
+        visit_u64(u64),
+        visit_u128(u128),
+        visit_f32(f32),
+        visit_f64(f64),
+        visit_char(char),
+        visit_bytes(&[u8]),
+        visit_borrowed_bytes(&'de [u8]),
+        visit_byte_buf(Vec<u8>),
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        if self.is_key {
+            self.delegate.visit_str(&rename(v, self.target))
+        } else {
+            self.delegate.visit_str(v)
+        }
+    }
+
+    fn visit_borrowed_str<E: de::Error>(
+        self,
+        v: &'de str,
+    ) -> Result<Self::Value, E> {
+        if !self.is_key {
+            return self.delegate.visit_borrowed_str(v);
+        }
+        match rename(v, self.target) {
+            Cow::Borrowed(_) => self.delegate.visit_borrowed_str(v),
+            Cow::Owned(renamed) => self.delegate.visit_string(renamed),
+        }
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        if !self.is_key {
+            return self.delegate.visit_string(v);
+        }
+        match rename(&v, self.target) {
+            Cow::Borrowed(_) => self.delegate.visit_string(v),
+            Cow::Owned(renamed) => self.delegate.visit_string(renamed),
+        }
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.delegate.visit_none()
+    }
+
+    fn visit_some<D: de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.delegate.visit_some(Deserializer::new(deserializer, self.target))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.delegate.visit_unit()
+    }
+
+    fn visit_newtype_struct<D: de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        let deserializer = Deserializer {
+            inner: deserializer,
+            target: self.target,
+            is_key: self.is_key,
+        };
+        self.delegate.visit_newtype_struct(deserializer)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(
+        self,
+        seq: A,
+    ) -> Result<Self::Value, A::Error> {
+        self.delegate.visit_seq(Access { inner: seq, target: self.target })
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(
+        self,
+        map: A,
+    ) -> Result<Self::Value, A::Error> {
+        self.delegate.visit_map(Access { inner: map, target: self.target })
+    }
+
+    fn visit_enum<A: de::EnumAccess<'de>>(
+        self,
+        data: A,
+    ) -> Result<Self::Value, A::Error> {
+        self.delegate.visit_enum(Access { inner: data, target: self.target })
+    }
+}
+
+/// Passes a [Deserializer] wrapper to the seed of a nested value or key.
+struct Seed<S> {
+    seed: S,
+    target: TargetCase,
+    is_key: bool,
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for Seed<S> {
+    type Value = S::Value;
+
+    fn deserialize<D: de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.seed.deserialize(Deserializer {
+            inner: deserializer,
+            target: self.target,
+            is_key: self.is_key,
+        })
+    }
+}
+
+/// Wraps the seq, map, enum and variant accessors of the inner deserializer.
+struct Access<A> {
+    inner: A,
+    target: TargetCase,
+}
+
+impl<A> Access<A> {
+    fn value<S>(&self, seed: S) -> Seed<S> {
+        Seed { seed, target: self.target, is_key: false }
+    }
+}
+
+impl<'de, A: de::SeqAccess<'de>> de::SeqAccess<'de> for Access<A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        let seed = self.value(seed);
+        self.inner.next_element_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: de::MapAccess<'de>> de::MapAccess<'de> for Access<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let seed = Seed { seed, target: self.target, is_key: true };
+        self.inner.next_key_seed(seed)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let seed = self.value(seed);
+        self.inner.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: de::EnumAccess<'de>> de::EnumAccess<'de> for Access<A> {
+    type Error = A::Error;
+    type Variant = Access<A::Variant>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        // Variant names are not keys, keep them as they are.
+        let (value, variant) = self.inner.variant_seed(seed)?;
+        Ok((value, Access { inner: variant, target: self.target }))
+    }
+}
+
+impl<'de, A: de::VariantAccess<'de>> de::VariantAccess<'de> for Access<A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        let seed = self.value(seed);
+        self.inner.newtype_variant_seed(seed)
+    }
+
+    fn tuple_variant<V: de::Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let visitor = Visitor::new(visitor, self.target, false);
+        self.inner.tuple_variant(len, visitor)
+    }
+
+    fn struct_variant<V: de::Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let visitor = Visitor::new(visitor, self.target, false);
+        self.inner.struct_variant(fields, visitor)
+    }
+}
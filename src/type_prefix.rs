@@ -0,0 +1,219 @@
+//! Single-letter prefixes that tell the role of a type,
+//! like `IDisposable` (interface), `TKey` (type parameter)
+//! or `EColor` (enum) in C# and Delphi.
+//!
+//! Such identifiers are pascal case, so the marker would become
+//! a word of its own when converted. Recognize it first,
+//! then choose a [PrefixPolicy] to keep, strip or normalize it.
+//!
+//! # Examples
+//!
+//! ```
+//! use naming_lib::type_prefix::{parse_type_prefix, PrefixPolicy, TypePrefix};
+//! use naming_lib::TargetCase;
+//!
+//! let parsed = parse_type_prefix("IHttpClient");
+//! assert_eq!(Some(TypePrefix::Interface), parsed.prefix);
+//! assert_eq!("HttpClient", parsed.name.as_str());
+//!
+//! assert_eq!("http_client", parsed.to_case(TargetCase::Snake, PrefixPolicy::Strip).unwrap());
+//! assert_eq!("i_http_client", parsed.to_case(TargetCase::Snake, PrefixPolicy::Keep).unwrap());
+//! assert_eq!("IHttpClient", parsed.to_case(TargetCase::Camel, PrefixPolicy::Normalize).unwrap());
+//!
+//! // Acronyms are not markers.
+//! assert_eq!(None, parse_type_prefix("IOError").prefix);
+//! assert_eq!(None, parse_type_prefix("IDs").prefix);
+//! ```
+
+use crate::{detector, CaseKind, NamingCase, TargetCase};
+
+/// Roles of types told by a single uppercase letter.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum TypePrefix {
+    /// `I`, like `IDisposable`.
+    Interface,
+    /// `T`, like `TKey`, also Delphi classes like `TForm`.
+    TypeParameter,
+    /// `E`, like `EColor`.
+    Enum,
+}
+
+impl TypePrefix {
+    /// All known type prefixes.
+    pub const ALL: [TypePrefix; 3] =
+        [TypePrefix::Interface, TypePrefix::TypeParameter, TypePrefix::Enum];
+
+    /// Get the marker letter.
+    pub fn letter(self) -> char {
+        match self {
+            TypePrefix::Interface => 'I',
+            TypePrefix::TypeParameter => 'T',
+            TypePrefix::Enum => 'E',
+        }
+    }
+}
+
+/// What to do with a recognized type prefix on conversion.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum PrefixPolicy {
+    /// Convert the marker as a word of its own,
+    /// like `i_http_client` or `iHttpClient`.
+    Keep,
+    /// Drop the marker, like `http_client`.
+    Strip,
+    /// Keep the marker as an uppercase letter attached to the name
+    /// in pascal case when the target is camel or pascal case,
+    /// like `IHttpClient`, so it is still recognized after conversion.
+    /// Other targets have separators,
+    /// where it's the same as [Keep](PrefixPolicy::Keep).
+    Normalize,
+}
+
+/// Result of recognizing a type prefix.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TypePrefixed<'a> {
+    /// The recognized prefix, [None] if there isn't one.
+    pub prefix: Option<TypePrefix>,
+    /// The rest of the identifier, or the whole one without a prefix.
+    pub name: NamingCase<'a>,
+}
+
+impl TypePrefixed<'_> {
+    /// Convert the name to the target case,
+    /// with the prefix handled by the policy.
+    ///
+    /// # Errors
+    ///
+    /// Get an [Err](core::result::Result::Err)
+    /// if the name is [Invalid](crate::CaseKind::Invalid).
+    pub fn to_case(
+        &self,
+        target: TargetCase,
+        policy: PrefixPolicy,
+    ) -> Result<String, &'static str> {
+        let prefix = match (self.prefix, policy) {
+            (None, _) | (Some(_), PrefixPolicy::Strip) => {
+                return self.name.to_case(target)
+            }
+            (Some(prefix), _) => prefix,
+        };
+
+        let whole = format!("{}{}", prefix.letter(), self.name.to_pascal()?);
+        match (policy, target) {
+            (
+                PrefixPolicy::Normalize,
+                TargetCase::Camel | TargetCase::Pascal,
+            ) => Ok(whole),
+            _ => detector::which_case(&whole).to_case(target),
+        }
+    }
+}
+
+/// Recognize type prefixes that are enabled,
+/// telling them from acronyms like `IO` or `ID`.
+///
+/// A prefix is recognized on a pascal case identifier
+/// if the marker letter is followed by a capitalized word,
+/// like `IDisposable`, but not `IOStream` or `Item`.
+/// Words listed as acronym exceptions, like `IDs` or `ETag`,
+/// are never treated as prefixed
+/// unless they are followed by a lowercase letter.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TypePrefixRecognizer {
+    prefixes: Vec<TypePrefix>,
+    exceptions: Vec<String>,
+}
+
+const ACRONYM_EXCEPTIONS: &[&str] =
+    &["IDs", "IOs", "IPs", "IPv4", "IPv6", "ETag", "ETags", "TVs"];
+
+impl TypePrefixRecognizer {
+    /// Create a recognizer of the given prefixes,
+    /// without any acronym exception.
+    pub fn new(prefixes: &[TypePrefix]) -> TypePrefixRecognizer {
+        TypePrefixRecognizer {
+            prefixes: prefixes.to_vec(),
+            exceptions: Vec::new(),
+        }
+    }
+
+    /// Never treat an identifier starting with this word as prefixed.
+    pub fn add_exception(&mut self, word: &str) {
+        if !self.exceptions.iter().any(|known| known == word) {
+            self.exceptions.push(word.to_string());
+        }
+    }
+
+    /// Forget an acronym exception, return true if it was known.
+    pub fn remove_exception(&mut self, word: &str) -> bool {
+        let before = self.exceptions.len();
+        self.exceptions.retain(|known| known != word);
+        before != self.exceptions.len()
+    }
+
+    /// Get all acronym exceptions, in the order they are added.
+    pub fn exceptions(&self) -> &[String] {
+        &self.exceptions
+    }
+
+    /// Separate the type prefix from an identifier,
+    /// the rest is recognized with [which_case()](crate::which_case()).
+    pub fn parse<'a>(&self, identifier: &'a str) -> TypePrefixed<'a> {
+        match self.recognize(identifier) {
+            Some(prefix) => TypePrefixed {
+                prefix: Some(prefix),
+                name: detector::which_case(&identifier[1..]),
+            },
+            None => TypePrefixed {
+                prefix: None,
+                name: detector::which_case(identifier),
+            },
+        }
+    }
+
+    fn recognize(&self, identifier: &str) -> Option<TypePrefix> {
+        if detector::which_case(identifier).kind() != CaseKind::Pascal {
+            return None;
+        }
+        let bytes = identifier.as_bytes();
+        if bytes.len() < 3
+            || !bytes[1].is_ascii_uppercase()
+            || !bytes[2].is_ascii_lowercase()
+        {
+            return None;
+        }
+        if self.is_exception(identifier) {
+            return None;
+        }
+        self.prefixes
+            .iter()
+            .copied()
+            .find(|prefix| prefix.letter() as u8 == bytes[0])
+    }
+
+    fn is_exception(&self, identifier: &str) -> bool {
+        self.exceptions.iter().any(|word| {
+            identifier.strip_prefix(word.as_str()).is_some_and(|rest| {
+                !rest.starts_with(|c: char| c.is_ascii_lowercase())
+            })
+        })
+    }
+}
+
+impl Default for TypePrefixRecognizer {
+    /// Recognize all known prefixes, with common acronyms as exceptions.
+    fn default() -> TypePrefixRecognizer {
+        let mut recognizer = TypePrefixRecognizer::new(&TypePrefix::ALL);
+        for word in ACRONYM_EXCEPTIONS {
+            recognizer.add_exception(word);
+        }
+        recognizer
+    }
+}
+
+/// Separate the type prefix from an identifier,
+/// same as [parse()](TypePrefixRecognizer::parse())
+/// on the [default()](TypePrefixRecognizer::default()) recognizer.
+pub fn parse_type_prefix(identifier: &str) -> TypePrefixed<'_> {
+    TypePrefixRecognizer::default().parse(identifier)
+}
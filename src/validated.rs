@@ -0,0 +1,211 @@
+//! Validated wrappers of identifiers in each naming format.
+//!
+//! A [NamingCase](crate::NamingCase) holds an identifier of any format,
+//! [Invalid](crate::CaseKind::Invalid) included, and tells which one by
+//! [kind()](crate::NamingCase::kind()).
+//! These types can only be created from strings that pass the matching
+//! detector, so the format is known from the type itself.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::convert::TryFrom;
+//! use naming_lib::{CamelCase, SnakeCase};
+//!
+//! let snake = SnakeCase::try_from("foo_bar").unwrap();
+//! assert_eq!("foo_bar", &*snake);
+//! assert!(SnakeCase::try_from("fooBar").is_err());
+//!
+//! let camel = CamelCase::from(snake);
+//! assert_eq!("fooBar", camel.as_str());
+//! ```
+
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::ops::Deref;
+
+use crate::{detector, TargetCase};
+
+macro_rules! validated_case {
+    ($(#[$doc:meta])* $name:ident, $detector:path, $error:literal) => {
+        $(#[$doc])*
+        #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Create a value if the identifier is in this format.
+            ///
+            /// # Errors
+            ///
+            /// Return an [Err](core::result::Result::Err)
+            /// if the identifier isn't in this format.
+            pub fn new(identifier: &str) -> Result<Self, &'static str> {
+                Self::try_from(identifier.to_string())
+            }
+
+            /// Get the included identifier.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Take the included identifier.
+            pub fn into_string(self) -> String {
+                self.0
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = &'static str;
+
+            fn try_from(identifier: String) -> Result<Self, Self::Error> {
+                if $detector(&identifier) {
+                    Ok($name(identifier))
+                } else {
+                    Err($error)
+                }
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = &'static str;
+
+            fn try_from(identifier: &str) -> Result<Self, Self::Error> {
+                Self::new(identifier)
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> String {
+                value.0
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                serializer.serialize_str(&self.0)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                let identifier = String::deserialize(deserializer)?;
+                $name::try_from(identifier).map_err(::serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+// A valid identifier can always be converted,
+// and the result always passes the detector of the target format.
+macro_rules! convert_between {
+    ($from:ident => $($to:ident: $target:expr),*) => {
+        $(
+            impl From<$from> for $to {
+                fn from(value: $from) -> $to {
+                    let converted = detector::which_case(&value.0)
+                        .to_case($target)
+                        .expect("a validated identifier can be converted");
+                    $to(converted)
+                }
+            }
+        )*
+    };
+}
+
+validated_case!(
+    /// An identifier in screaming snake case, like `FOO_BAR`.
+    ScreamingSnakeCase,
+    detector::is_screaming_snake,
+    "This identifier isn't in screaming snake case."
+);
+
+validated_case!(
+    /// An identifier in snake case, like `foo_bar`.
+    SnakeCase,
+    detector::is_snake,
+    "This identifier isn't in snake case."
+);
+
+validated_case!(
+    /// An identifier in kebab case, like `foo-bar`.
+    KebabCase,
+    detector::is_kebab,
+    "This identifier isn't in kebab case."
+);
+
+validated_case!(
+    /// An identifier in camel case, like `fooBar`.
+    CamelCase,
+    detector::is_camel,
+    "This identifier isn't in camel case."
+);
+
+validated_case!(
+    /// An identifier in pascal case, like `FooBar`.
+    PascalCase,
+    detector::is_pascal,
+    "This identifier isn't in pascal case."
+);
+
+convert_between!(ScreamingSnakeCase =>
+    SnakeCase: TargetCase::Snake,
+    KebabCase: TargetCase::Kebab,
+    CamelCase: TargetCase::Camel,
+    PascalCase: TargetCase::Pascal
+);
+
+convert_between!(SnakeCase =>
+    ScreamingSnakeCase: TargetCase::ScreamingSnake,
+    KebabCase: TargetCase::Kebab,
+    CamelCase: TargetCase::Camel,
+    PascalCase: TargetCase::Pascal
+);
+
+convert_between!(KebabCase =>
+    ScreamingSnakeCase: TargetCase::ScreamingSnake,
+    SnakeCase: TargetCase::Snake,
+    CamelCase: TargetCase::Camel,
+    PascalCase: TargetCase::Pascal
+);
+
+convert_between!(CamelCase =>
+    ScreamingSnakeCase: TargetCase::ScreamingSnake,
+    SnakeCase: TargetCase::Snake,
+    KebabCase: TargetCase::Kebab,
+    PascalCase: TargetCase::Pascal
+);
+
+convert_between!(PascalCase =>
+    ScreamingSnakeCase: TargetCase::ScreamingSnake,
+    SnakeCase: TargetCase::Snake,
+    KebabCase: TargetCase::Kebab,
+    CamelCase: TargetCase::Camel
+);
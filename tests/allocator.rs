@@ -0,0 +1,52 @@
+use naming_lib::allocator::NameAllocator;
+use naming_lib::TargetCase;
+
+#[test]
+fn append_numbers_in_target_case() {
+    let expected = [
+        (TargetCase::ScreamingSnake, ["FOO_BAR", "FOO_BAR_2", "FOO_BAR_3"]),
+        (TargetCase::Snake, ["foo_bar", "foo_bar_2", "foo_bar_3"]),
+        (TargetCase::Kebab, ["foo-bar", "foo-bar-2", "foo-bar-3"]),
+        (TargetCase::Camel, ["fooBar", "fooBar2", "fooBar3"]),
+        (TargetCase::Pascal, ["FooBar", "FooBar2", "FooBar3"]),
+    ];
+
+    for (target, names) in expected {
+        let mut allocator = NameAllocator::new(target);
+        let allocated: Vec<String> = ["fooBar", "foo_bar", "FOO-BAR"]
+            .iter()
+            .map(|raw| allocator.allocate(raw).unwrap())
+            .collect();
+        assert_eq!(names.to_vec(), allocated);
+    }
+}
+
+#[test]
+fn avoid_reserved_names() {
+    let mut allocator = NameAllocator::new(TargetCase::Snake);
+    allocator.reserve("type");
+    allocator.reserve("type_2");
+
+    assert!(allocator.is_taken("type"));
+    assert_eq!("type_3", allocator.allocate("Type").unwrap());
+    assert_eq!("type_4", allocator.allocate("type").unwrap());
+}
+
+#[test]
+fn track_issued_names_across_calls() {
+    let mut allocator = NameAllocator::new(TargetCase::Camel);
+    assert_eq!("fooBar2", allocator.allocate("fooBar2").unwrap());
+    assert_eq!("fooBar", allocator.allocate("fooBar").unwrap());
+    assert_eq!("fooBar3", allocator.allocate("foo-bar").unwrap());
+
+    let mut issued: Vec<&str> = allocator.issued().collect();
+    issued.sort_unstable();
+    assert_eq!(vec!["fooBar", "fooBar2", "fooBar3"], issued);
+}
+
+#[test]
+fn accept_raw_strings_with_words_only() {
+    let mut allocator = NameAllocator::new(TargetCase::Pascal);
+    assert_eq!("UserId", allocator.allocate("user id").unwrap());
+    assert!(allocator.allocate("@@").is_err());
+}
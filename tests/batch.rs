@@ -0,0 +1,82 @@
+use naming_lib::batch::{convert_batch, Disambiguate};
+use naming_lib::TargetCase;
+
+#[test]
+fn report_every_clashing_group() {
+    let ids = ["fooBar", "foo_bar", "userId", "FOO_BAR", "USER_ID", "bar"];
+    let result = convert_batch(ids, TargetCase::Snake, &Disambiguate::No);
+
+    assert_eq!(2, result.collisions.len());
+    assert_eq!("foo_bar", result.collisions[0].converted);
+    assert_eq!(
+        vec!["fooBar", "foo_bar", "FOO_BAR"],
+        result.collisions[0].identifiers
+    );
+    assert_eq!(vec![0, 1, 3], result.collisions[0].indices);
+    assert_eq!("user_id", result.collisions[1].converted);
+    assert_eq!(vec![2, 4], result.collisions[1].indices);
+
+    // Names are untouched without disambiguation.
+    assert_eq!(
+        vec!["foo_bar", "foo_bar", "user_id", "foo_bar", "user_id", "bar"],
+        result.names()
+    );
+}
+
+#[test]
+fn repeated_identifiers_do_not_collide() {
+    let ids = ["fooBar", "fooBar"];
+    let result = convert_batch(ids, TargetCase::Kebab, &Disambiguate::Numeric);
+
+    assert!(result.collisions.is_empty());
+    assert_eq!(vec!["foo-bar", "foo-bar"], result.names());
+}
+
+#[test]
+fn append_numbers_in_target_case() {
+    let ids = ["fooBar", "foo_bar", "foo-bar"];
+    let expected = [
+        (TargetCase::ScreamingSnake, ["FOO_BAR", "FOO_BAR_2", "FOO_BAR_3"]),
+        (TargetCase::Snake, ["foo_bar", "foo_bar_2", "foo_bar_3"]),
+        (TargetCase::Kebab, ["foo-bar", "foo-bar-2", "foo-bar-3"]),
+        (TargetCase::Camel, ["fooBar", "fooBar2", "fooBar3"]),
+        (TargetCase::Pascal, ["FooBar", "FooBar2", "FooBar3"]),
+    ];
+
+    for (target, names) in expected {
+        let result = convert_batch(ids, target, &Disambiguate::Numeric);
+        assert_eq!(names.to_vec(), result.names());
+    }
+}
+
+#[test]
+fn skip_suffixed_names_already_used() {
+    let ids = ["fooBar2", "fooBar", "foo_bar"];
+    let result = convert_batch(ids, TargetCase::Camel, &Disambiguate::Numeric);
+
+    assert_eq!(vec!["fooBar2", "fooBar", "fooBar3"], result.names());
+}
+
+#[test]
+fn append_given_suffixes_then_numbers() {
+    let ids = ["fooBar", "foo_bar", "FOO_BAR", "FooBar"];
+    let suffixes = Disambiguate::Suffixes(vec![
+        "alt".to_string(),
+        "old_value".to_string(),
+    ]);
+    let result = convert_batch(ids, TargetCase::Camel, &suffixes);
+
+    assert_eq!(
+        vec!["fooBar", "fooBarAlt", "fooBarOldValue", "fooBar2"],
+        result.names()
+    );
+}
+
+#[test]
+fn keep_errors_for_invalid_identifiers() {
+    let ids = ["foo bar", "fooBar"];
+    let result = convert_batch(ids, TargetCase::Snake, &Disambiguate::Numeric);
+
+    assert!(result.converted[0].is_err());
+    assert_eq!(vec!["foo_bar"], result.names());
+}
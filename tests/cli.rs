@@ -0,0 +1,237 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn naming(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_naming"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout_of(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn detect_cases_of_arguments() {
+    let output = naming(&["detect", "fooBar", "FOO_BAR", "foo"], "");
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!("camel\nscreaming-snake\nsingle-word\n", stdout_of(&output));
+}
+
+#[test]
+fn convert_lines_from_stdin() {
+    let output = naming(&["convert", "--to", "snake"], "fooBar\n\nFooBaz\r\n");
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!("foo_bar\nfoo_baz\n", stdout_of(&output));
+
+    let output = naming(&["convert", "--to=screaming-snake", "foo-bar"], "");
+    assert_eq!("FOO_BAR\n", stdout_of(&output));
+}
+
+#[test]
+fn print_words() {
+    let output = naming(&["words", "fooBarBaz", "foo_bar"], "");
+    assert_eq!("foo Bar Baz\nfoo bar\n", stdout_of(&output));
+}
+
+#[test]
+fn exit_with_one_on_invalid_identifiers() {
+    let output =
+        naming(&["convert", "--to", "camel", "foo_bar", "foo bar"], "");
+    assert_eq!(Some(1), output.status.code());
+    assert_eq!("fooBar\n", stdout_of(&output));
+    assert!(!output.stderr.is_empty());
+
+    let output = naming(&["detect"], "foo@bar\n");
+    assert_eq!(Some(1), output.status.code());
+    assert_eq!("invalid\n", stdout_of(&output));
+}
+
+#[test]
+fn print_json_lines() {
+    let output = naming(&["convert", "--json", "--to", "kebab", "fooBar"], "");
+    assert_eq!(
+        "{\"identifier\":\"fooBar\",\"case\":\"camel\",\"converted\":\"foo-bar\"}\n",
+        stdout_of(&output)
+    );
+
+    let output = naming(&["words", "--json"], "FooBar\n\"a\\b\"\n");
+    assert_eq!(Some(1), output.status.code());
+    let lines: Vec<serde_json::Value> = stdout_of(&output)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(serde_json::json!(["Foo", "Bar"]), lines[0]["words"]);
+    assert_eq!("\"a\\b\"", lines[1]["identifier"]);
+    assert_eq!("invalid", lines[1]["case"]);
+    assert!(lines[1]["error"].is_string());
+}
+
+#[test]
+fn lint_source_trees() {
+    let root = std::env::temp_dir()
+        .join(format!("naming_lib-cli-lint-{}", std::process::id()));
+    std::fs::create_dir_all(&root).unwrap();
+    std::fs::write(root.join("main.rs"), "fn mainLoop() {}\n").unwrap();
+    std::fs::write(root.join("util.py"), "def run_all(): pass\n").unwrap();
+    let root_str = root.to_str().unwrap();
+
+    let output = naming(&["lint", root_str], "");
+    assert_eq!(Some(1), output.status.code());
+    assert_eq!(
+        format!(
+            "{}:1:4: function `mainLoop` should be snake case, \
+             found camel (try `main_loop`)\n",
+            root.join("main.rs").display()
+        ),
+        stdout_of(&output)
+    );
+
+    let output = naming(&["lint", "--json", root_str], "");
+    let json: serde_json::Value =
+        serde_json::from_str(stdout_of(&output).trim()).unwrap();
+    assert_eq!("mainLoop", json["identifier"]);
+    assert_eq!("function", json["kind"]);
+    assert_eq!("camel", json["case"]);
+    assert_eq!("snake", json["expected"]);
+    assert_eq!("main_loop", json["suggestion"]);
+    assert_eq!(4, json["column"]);
+
+    let output = naming(&["lint", &format!("{}/util.py", root_str)], "");
+    assert_eq!(Some(0), output.status.code());
+    assert!(output.stdout.is_empty());
+
+    let output = naming(&["lint", &format!("{}/missing", root_str)], "");
+    assert_eq!(Some(3), output.status.code());
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn lint_in_report_formats() {
+    let root = std::env::temp_dir()
+        .join(format!("naming_lib-cli-format-{}", std::process::id()));
+    std::fs::create_dir_all(root.join("a")).unwrap();
+    std::fs::write(root.join("a/main.rs"), "fn mainLoop() {}\n").unwrap();
+    std::fs::write(root.join("b.go"), "type http_client struct{}\n").unwrap();
+    let a = root.join("a");
+    let b = root.join("b.go");
+    let paths = [a.to_str().unwrap(), b.to_str().unwrap()];
+
+    // One document for all paths.
+    let output = naming(&["lint", "--format", "sarif", paths[0], paths[1]], "");
+    assert_eq!(Some(1), output.status.code());
+    let sarif: serde_json::Value =
+        serde_json::from_str(&stdout_of(&output)).unwrap();
+    assert_eq!(2, sarif["runs"][0]["results"].as_array().unwrap().len());
+
+    let output =
+        naming(&["lint", "--format=checkstyle", paths[0], paths[1]], "");
+    let xml = stdout_of(&output);
+    assert_eq!(1, xml.matches("<checkstyle ").count());
+    assert_eq!(2, xml.matches("<file ").count());
+
+    let output = naming(&["lint", "--format", "github", paths[1]], "");
+    assert!(stdout_of(&output).starts_with("::warning file="));
+
+    // `--format` wins over `--json`.
+    let output = naming(&["lint", "--json", "--format", "text", paths[1]], "");
+    assert!(stdout_of(&output).contains("type `http_client`"));
+
+    let output = naming(&["lint", "--format", "xml", paths[1]], "");
+    assert_eq!(Some(2), output.status.code());
+    let output = naming(&["detect", "--format", "json", "fooBar"], "");
+    assert_eq!(Some(2), output.status.code());
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn fix_lint_violations() {
+    let root = std::env::temp_dir()
+        .join(format!("naming_lib-cli-fix-{}", std::process::id()));
+    std::fs::create_dir_all(&root).unwrap();
+    let source = "def getUserName():\n    pass\n\n\
+                  def getUser():\n    return getUserName()\n\n\
+                  get_user = None\n";
+    std::fs::write(root.join("a.py"), source).unwrap();
+    let root_str = root.to_str().unwrap();
+
+    let output = naming(&["lint", "--diff", root_str], "");
+    assert_eq!(Some(1), output.status.code());
+    let diff = stdout_of(&output);
+    assert!(diff.contains("-def getUserName():\n+def get_user_name():\n"));
+    assert!(diff.contains("+    return get_user_name()\n"));
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("can't rename `getUser` to `get_user`"));
+    // Nothing is written.
+    assert_eq!(source, std::fs::read_to_string(root.join("a.py")).unwrap());
+
+    let output = naming(&["lint", "--fix", "--format", "json", root_str], "");
+    assert_eq!(Some(1), output.status.code());
+    let left: serde_json::Value =
+        serde_json::from_str(stdout_of(&output).trim()).unwrap();
+    assert_eq!("getUser", left["identifier"]);
+    assert_eq!(
+        source.replace("getUserName", "get_user_name"),
+        std::fs::read_to_string(root.join("a.py")).unwrap()
+    );
+
+    let output = naming(&["lint", "--fix", "--diff", root_str], "");
+    assert_eq!(Some(2), output.status.code());
+    let output = naming(&["lint", "--scope", "project", root_str], "");
+    assert_eq!(Some(2), output.status.code());
+    let output = naming(&["detect", "--fix", "fooBar"], "");
+    assert_eq!(Some(2), output.status.code());
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn lint_with_config_files() {
+    let root = std::env::temp_dir()
+        .join(format!("naming_lib-cli-config-{}", std::process::id()));
+    std::fs::create_dir_all(root.join("src")).unwrap();
+    std::fs::write(root.join(".naming.toml"), "[rust]\nfunction = \"camel\"\n")
+        .unwrap();
+    std::fs::write(root.join("src/main.rs"), "fn mainLoop() {}\n").unwrap();
+
+    let output = naming(&["lint", root.join("src").to_str().unwrap()], "");
+    assert_eq!(Some(0), output.status.code());
+
+    std::fs::write(root.join("src/.naming.toml"), "[rust]\nfunction = 1\n")
+        .unwrap();
+    let output = naming(&["lint", root.join("src").to_str().unwrap()], "");
+    assert_eq!(Some(3), output.status.code());
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("`rust.function`: Expected a string."));
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn exit_with_two_on_usage_errors() {
+    for args in [
+        &[][..],
+        &["rename"],
+        &["convert", "fooBar"],
+        &["convert", "--to", "upper"],
+        &["detect", "--to", "snake"],
+        &["detect", "--verbose"],
+        &["lint", "--to", "snake"],
+    ] {
+        let output = naming(args, "");
+        assert_eq!(Some(2), output.status.code(), "{:?}", args);
+    }
+
+    let output = naming(&["--help"], "");
+    assert_eq!(Some(0), output.status.code());
+    assert!(stdout_of(&output).starts_with("Usage:"));
+}
@@ -0,0 +1,60 @@
+// Compare outputs with the real heck crate on random strings.
+
+#[cfg(test)]
+extern crate quickcheck;
+#[cfg(test)]
+#[macro_use(quickcheck)]
+extern crate quickcheck_macros;
+
+use naming_lib::compat::heck::*;
+
+#[quickcheck]
+fn same_snake_and_kebab_case_as_heck(s: String) -> bool {
+    s.to_snake_case() == heck::ToSnakeCase::to_snake_case(s.as_str())
+        && s.to_snek_case() == heck::ToSnekCase::to_snek_case(s.as_str())
+        && s.to_kebab_case() == heck::ToKebabCase::to_kebab_case(s.as_str())
+}
+
+#[quickcheck]
+fn same_camel_case_as_heck(s: String) -> bool {
+    s.to_lower_camel_case()
+        == heck::ToLowerCamelCase::to_lower_camel_case(s.as_str())
+        && s.to_upper_camel_case()
+            == heck::ToUpperCamelCase::to_upper_camel_case(s.as_str())
+        && s.to_pascal_case() == heck::ToPascalCase::to_pascal_case(s.as_str())
+}
+
+#[quickcheck]
+fn same_shouty_case_as_heck(s: String) -> bool {
+    s.to_shouty_snake_case()
+        == heck::ToShoutySnakeCase::to_shouty_snake_case(s.as_str())
+        && s.TO_SHOUTY_SNEK_CASE()
+            == heck::ToShoutySnekCase::TO_SHOUTY_SNEK_CASE(s.as_str())
+        && s.to_shouty_kebab_case()
+            == heck::ToShoutyKebabCase::to_shouty_kebab_case(s.as_str())
+}
+
+#[quickcheck]
+fn same_title_and_train_case_as_heck(s: String) -> bool {
+    s.to_title_case() == heck::ToTitleCase::to_title_case(s.as_str())
+        && s.to_train_case() == heck::ToTrainCase::to_train_case(s.as_str())
+}
+
+#[test]
+fn acronyms_and_digits_are_split_as_heck_documents() {
+    let table = [
+        ("XMLHttpRequest", "xml_http_request"),
+        ("FIELD_NAME11", "field_name11"),
+        ("99BOTTLES", "99bottles"),
+        ("FieldNamE11", "field_nam_e11"),
+        ("abc123DEF456", "abc123_def456"),
+        ("abc123DEf456", "abc123_d_ef456"),
+        ("ABC123dEEf456FOO", "abc123d_e_ef456_foo"),
+        ("ABcDE", "a_bc_de"),
+        ("XΣXΣ baﬄe", "xσxς_baﬄe"),
+    ];
+
+    for (input, snake) in table {
+        assert_eq!(snake, input.to_snake_case());
+    }
+}
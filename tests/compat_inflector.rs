@@ -0,0 +1,58 @@
+// Compare outputs with the real Inflector crate on random strings.
+
+#[cfg(test)]
+extern crate quickcheck;
+#[cfg(test)]
+#[macro_use(quickcheck)]
+extern crate quickcheck_macros;
+
+use naming_lib::compat::inflector::Inflector;
+
+#[quickcheck]
+fn same_snake_like_cases_as_inflector(s: String) -> bool {
+    let s = s.as_str();
+    s.to_snake_case() == inflector::Inflector::to_snake_case(s)
+        && s.is_snake_case() == inflector::Inflector::is_snake_case(s)
+        && s.to_screaming_snake_case()
+            == inflector::Inflector::to_screaming_snake_case(s)
+        && s.is_screaming_snake_case()
+            == inflector::Inflector::is_screaming_snake_case(s)
+        && s.to_kebab_case() == inflector::Inflector::to_kebab_case(s)
+        && s.is_kebab_case() == inflector::Inflector::is_kebab_case(s)
+}
+
+#[quickcheck]
+fn same_camel_like_cases_as_inflector(s: String) -> bool {
+    let s = s.as_str();
+    s.to_camel_case() == inflector::Inflector::to_camel_case(s)
+        && s.is_camel_case() == inflector::Inflector::is_camel_case(s)
+        && s.to_pascal_case() == inflector::Inflector::to_pascal_case(s)
+        && s.is_pascal_case() == inflector::Inflector::is_pascal_case(s)
+        && s.to_train_case() == inflector::Inflector::to_train_case(s)
+        && s.is_train_case() == inflector::Inflector::is_train_case(s)
+}
+
+#[quickcheck]
+fn same_sentence_and_title_case_as_inflector(s: String) -> bool {
+    let s = s.as_str();
+    s.to_sentence_case() == inflector::Inflector::to_sentence_case(s)
+        && s.is_sentence_case() == inflector::Inflector::is_sentence_case(s)
+        && s.to_title_case() == inflector::Inflector::to_title_case(s)
+        && s.is_title_case() == inflector::Inflector::is_title_case(s)
+}
+
+#[test]
+fn identifiers_are_converted_as_inflector_does() {
+    let table = [
+        ("FooBar", "foo_bar", "fooBar"),
+        ("HTTPServer", "http_server", "httpserver"),
+        ("foo1bar", "foo_1bar", "foo1Bar"),
+        ("Foo bar", "foo_bar", "fooBar"),
+        ("--foo--bar--", "foo_bar", "fooBar"),
+    ];
+
+    for (input, snake, camel) in table {
+        assert_eq!(snake, input.to_snake_case());
+        assert_eq!(camel, input.to_camel_case());
+    }
+}
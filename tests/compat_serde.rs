@@ -0,0 +1,232 @@
+// Conformance tests of serde's `rename_all` rules,
+// against a fixed table and against what serde derive really emits.
+
+use serde::Serialize;
+
+use naming_lib::compat::serde::RenameRule::{self, *};
+
+const RULES: [RenameRule; 8] = [
+    LowerCase,
+    UpperCase,
+    PascalCase,
+    CamelCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    KebabCase,
+    ScreamingKebabCase,
+];
+
+#[test]
+fn rules_are_parsed_from_attribute_values() {
+    for rule in RULES {
+        assert_eq!(Ok(rule), rule.name().parse());
+    }
+    assert!("Snake_Case".parse::<RenameRule>().is_err());
+}
+
+#[test]
+fn rename_variants_by_table() {
+    // original, lower, UPPER, camel, snake, SCREAMING, kebab, SCREAMING-KEBAB
+    let table = [
+        [
+            "Outcome", "outcome", "OUTCOME", "outcome", "outcome", "OUTCOME",
+            "outcome", "OUTCOME",
+        ],
+        [
+            "VeryTasty",
+            "verytasty",
+            "VERYTASTY",
+            "veryTasty",
+            "very_tasty",
+            "VERY_TASTY",
+            "very-tasty",
+            "VERY-TASTY",
+        ],
+        ["A", "a", "A", "a", "a", "A", "a", "A"],
+        ["Z42", "z42", "Z42", "z42", "z42", "Z42", "z42", "Z42"],
+        [
+            "HTTPServer",
+            "httpserver",
+            "HTTPSERVER",
+            "hTTPServer",
+            "h_t_t_p_server",
+            "H_T_T_P_SERVER",
+            "h-t-t-p-server",
+            "H-T-T-P-SERVER",
+        ],
+        [
+            "Version2Beta",
+            "version2beta",
+            "VERSION2BETA",
+            "version2Beta",
+            "version2_beta",
+            "VERSION2_BETA",
+            "version2-beta",
+            "VERSION2-BETA",
+        ],
+    ];
+
+    for row in table {
+        let [original, lower, upper, camel, snake, screaming, kebab, screaming_kebab] =
+            row;
+        assert_eq!(lower, LowerCase.apply_to_variant(original));
+        assert_eq!(upper, UpperCase.apply_to_variant(original));
+        assert_eq!(original, PascalCase.apply_to_variant(original));
+        assert_eq!(camel, CamelCase.apply_to_variant(original));
+        assert_eq!(snake, SnakeCase.apply_to_variant(original));
+        assert_eq!(screaming, ScreamingSnakeCase.apply_to_variant(original));
+        assert_eq!(kebab, KebabCase.apply_to_variant(original));
+        assert_eq!(
+            screaming_kebab,
+            ScreamingKebabCase.apply_to_variant(original)
+        );
+    }
+}
+
+#[test]
+fn rename_fields_by_table() {
+    // original, UPPER, Pascal, camel, SCREAMING, kebab, SCREAMING-KEBAB
+    let table = [
+        [
+            "outcome", "OUTCOME", "Outcome", "outcome", "OUTCOME", "outcome",
+            "OUTCOME",
+        ],
+        [
+            "very_tasty",
+            "VERY_TASTY",
+            "VeryTasty",
+            "veryTasty",
+            "VERY_TASTY",
+            "very-tasty",
+            "VERY-TASTY",
+        ],
+        ["a", "A", "A", "a", "A", "a", "A"],
+        ["z42", "Z42", "Z42", "z42", "Z42", "z42", "Z42"],
+        [
+            "version_2",
+            "VERSION_2",
+            "Version2",
+            "version2",
+            "VERSION_2",
+            "version-2",
+            "VERSION-2",
+        ],
+        [
+            "io__error",
+            "IO__ERROR",
+            "IoError",
+            "ioError",
+            "IO__ERROR",
+            "io--error",
+            "IO--ERROR",
+        ],
+    ];
+
+    for row in table {
+        let [original, upper, pascal, camel, screaming, kebab, screaming_kebab] =
+            row;
+        assert_eq!(original, LowerCase.apply_to_field(original));
+        assert_eq!(upper, UpperCase.apply_to_field(original));
+        assert_eq!(pascal, PascalCase.apply_to_field(original));
+        assert_eq!(camel, CamelCase.apply_to_field(original));
+        assert_eq!(original, SnakeCase.apply_to_field(original));
+        assert_eq!(screaming, ScreamingSnakeCase.apply_to_field(original));
+        assert_eq!(kebab, KebabCase.apply_to_field(original));
+        assert_eq!(
+            screaming_kebab,
+            ScreamingKebabCase.apply_to_field(original)
+        );
+    }
+}
+
+// For each rule, derive an enum and a struct with that `rename_all`,
+// return the serialized variant names and field names.
+macro_rules! serde_derive_outputs {
+    ($($rule:ident => $name:literal),* $(,)?) => {
+        #[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+        fn serde_derive_outputs(rule: RenameRule) -> (Vec<String>, Vec<String>) {
+            $(
+                if rule == $rule {
+                    #[derive(Serialize)]
+                    #[serde(rename_all = $name)]
+                    enum Variants {
+                        Outcome,
+                        VeryTasty,
+                        A,
+                        Z42,
+                        HTTPServer,
+                        Version2Beta,
+                    }
+
+                    #[derive(Serialize, Default)]
+                    #[serde(rename_all = $name)]
+                    #[allow(non_snake_case)]
+                    struct Fields {
+                        outcome: u8,
+                        very_tasty: u8,
+                        a: u8,
+                        z42: u8,
+                        version_2: u8,
+                        io__error: u8,
+                    }
+
+                    let variants = [
+                        Variants::Outcome,
+                        Variants::VeryTasty,
+                        Variants::A,
+                        Variants::Z42,
+                        Variants::HTTPServer,
+                        Variants::Version2Beta,
+                    ]
+                    .iter()
+                    .map(|v| serde_json::to_value(v).unwrap().as_str().unwrap().to_string())
+                    .collect();
+
+                    let fields = serde_json::to_value(Fields::default())
+                        .unwrap()
+                        .as_object()
+                        .unwrap()
+                        .keys()
+                        .cloned()
+                        .collect();
+
+                    return (variants, fields);
+                }
+            )*
+            unreachable!()
+        }
+    };
+}
+
+serde_derive_outputs! {
+    LowerCase => "lowercase",
+    UpperCase => "UPPERCASE",
+    PascalCase => "PascalCase",
+    CamelCase => "camelCase",
+    SnakeCase => "snake_case",
+    ScreamingSnakeCase => "SCREAMING_SNAKE_CASE",
+    KebabCase => "kebab-case",
+    ScreamingKebabCase => "SCREAMING-KEBAB-CASE",
+}
+
+#[test]
+fn same_outputs_as_serde_derive() {
+    let variants =
+        ["Outcome", "VeryTasty", "A", "Z42", "HTTPServer", "Version2Beta"];
+    let fields =
+        ["outcome", "very_tasty", "a", "z42", "version_2", "io__error"];
+
+    for rule in RULES {
+        let (serde_variants, serde_fields) = serde_derive_outputs(rule);
+
+        let our_variants: Vec<String> =
+            variants.iter().map(|v| rule.apply_to_variant(v)).collect();
+        assert_eq!(serde_variants, our_variants, "{}", rule);
+
+        // serde_json sorts object keys.
+        let mut our_fields: Vec<String> =
+            fields.iter().map(|f| rule.apply_to_field(f)).collect();
+        our_fields.sort();
+        assert_eq!(serde_fields, our_fields, "{}", rule);
+    }
+}
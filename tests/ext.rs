@@ -0,0 +1,74 @@
+#[cfg(test)]
+extern crate quickcheck;
+#[cfg(test)]
+#[macro_use(quickcheck)]
+extern crate quickcheck_macros;
+
+use std::borrow::Cow;
+
+use quickcheck::TestResult;
+
+use common::*;
+use naming_lib as lib;
+use naming_lib::{NamingCaseExt, TargetCase};
+
+mod common;
+
+const TARGETS: [TargetCase; 5] = [
+    TargetCase::ScreamingSnake,
+    TargetCase::Snake,
+    TargetCase::Kebab,
+    TargetCase::Camel,
+    TargetCase::Pascal,
+];
+
+#[quickcheck]
+fn same_results_as_naming_case(s: String) -> bool {
+    let case = lib::which_case(&s);
+    s.naming_case() == case
+        && TARGETS.iter().all(|&target| {
+            s.to_case(target).map(Cow::into_owned) == case.to_case(target)
+        })
+}
+
+#[quickcheck]
+fn borrow_identifiers_already_in_target_case(word: String) -> TestResult {
+    // Repeated single letters like "AAA" are recognized as single words.
+    if is_not_valid_single_word(&word) || word.len() < 2 {
+        return TestResult::discard();
+    }
+
+    let identifiers = build_all_format_str(word);
+    let all_borrowed =
+        identifiers.iter().zip(TARGETS.iter()).all(|(identifier, &target)| {
+            matches!(identifier.to_case(target), Ok(Cow::Borrowed(_)))
+        });
+    TestResult::from_bool(all_borrowed)
+}
+
+#[test]
+fn work_on_str_string_and_cow() {
+    assert!("fooBar".is_camel_case());
+    assert!(String::from("foo_bar").is_snake_case());
+    assert!(Cow::Borrowed("FooBar").is_pascal_case());
+
+    assert_eq!("foo-bar", "fooBar".to_kebab_case().unwrap());
+    assert_eq!(
+        "FOO_BAR",
+        String::from("foo-bar").to_screaming_snake_case().unwrap()
+    );
+    assert_eq!("fooBar", Cow::Borrowed("FooBar").to_camel_case().unwrap());
+}
+
+#[test]
+fn convert_single_words_like_naming_case() {
+    assert!(matches!("FOO".to_pascal_case(), Ok(Cow::Owned(s)) if s == "Foo"));
+    assert!(matches!("Foo".to_pascal_case(), Ok(Cow::Borrowed("Foo"))));
+    assert!(matches!("foo".to_camel_case(), Ok(Cow::Borrowed("foo"))));
+}
+
+#[test]
+fn return_errors_for_invalid_identifiers() {
+    assert!("foo bar".to_snake_case().is_err());
+    assert!(!"foo bar".is_snake_case());
+}
@@ -0,0 +1,278 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use naming_lib::from;
+use naming_lib::language::Language;
+use naming_lib::lint::{FileFix, Fixer, Fixes, Linter, Scope};
+
+/// A directory of source files, removed on drop.
+struct TempTree(PathBuf);
+
+impl TempTree {
+    fn new(name: &str, files: &[(&str, &str)]) -> TempTree {
+        let root = std::env::temp_dir().join(format!(
+            "naming_lib-fix-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        for (path, content) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        TempTree(root)
+    }
+}
+
+impl Drop for TempTree {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn fix(scope: Scope, sources: &[(&str, Language, &str)]) -> Fixes {
+    let mut fixer = Fixer::new(scope);
+    for (path, language, source) in sources {
+        fixer.add_source(
+            &Linter::default(),
+            Path::new(path),
+            *language,
+            source,
+        );
+    }
+    fixer.fix()
+}
+
+fn refused(fixes: &Fixes) -> Vec<(&str, &str)> {
+    fixes
+        .refused
+        .iter()
+        .map(|r| (r.diagnostic.identifier.as_str(), r.reason))
+        .collect()
+}
+
+#[test]
+fn parse_scope_names() {
+    assert_eq!(Ok(Scope::File), "file".parse());
+    assert_eq!(Ok(Scope::Project), Scope::Project.name().parse());
+    assert_eq!(Err("Unknown scope name."), "crate".parse::<Scope>());
+    assert_eq!(Scope::File, Scope::default());
+}
+
+#[test]
+fn rename_references_in_file() {
+    let source = "\
+class userAccount:
+    def getUserName(self):
+        # getUserName in comments and strings is kept
+        return \"getUserName\"
+
+def show(account: userAccount):
+    print(account.getUserName())
+";
+    let fixes = fix(Scope::File, &[("a.py", Language::Python, source)]);
+
+    assert_eq!(1, fixes.files.len());
+    assert_eq!(
+        "\
+class UserAccount:
+    def get_user_name(self):
+        # getUserName in comments and strings is kept
+        return \"getUserName\"
+
+def show(account: UserAccount):
+    print(account.get_user_name())
+",
+        fixes.files[0].fixed
+    );
+    assert_eq!(source, fixes.files[0].original);
+    assert_eq!(2, fixes.fixed.len());
+    assert!(fixes.refused.is_empty());
+}
+
+#[test]
+fn rename_as_naming_case_converts() {
+    let identifiers = ["parseArgs", "loadV2Config", "Abc123Def"];
+    let source: String = identifiers
+        .iter()
+        .map(|identifier| format!("fn {}() {{}}\n", identifier))
+        .collect();
+    let fixes = fix(Scope::File, &[("a.rs", Language::Rust, &source)]);
+
+    let expected: String = identifiers
+        .iter()
+        .map(|identifier| {
+            format!("fn {}() {{}}\n", from(identifier).to_snake().unwrap())
+        })
+        .collect();
+    assert_eq!(expected, fixes.files[0].fixed);
+}
+
+#[test]
+fn refuse_colliding_renames() {
+    let source = "\
+fn getName() {}
+fn get_name() {}
+fn fooBar() {}
+fn FooBar() {}
+fn parseArgs() {}
+";
+    let fixes = fix(Scope::File, &[("a.rs", Language::Rust, source)]);
+
+    assert_eq!(
+        vec![
+            ("getName", "Collides with an existing name."),
+            ("fooBar", "Collides with another rename."),
+            ("FooBar", "Collides with another rename."),
+        ],
+        refused(&fixes)
+    );
+    assert_eq!(source.replace("parseArgs", "parse_args"), fixes.files[0].fixed);
+
+    // Types and functions of the same names.
+    let fixes = fix(
+        Scope::File,
+        &[(
+            "a.rs",
+            Language::Rust,
+            "struct http_client;\nfn http_client() {}\n\
+             struct fooBar;\nfn fooBar() {}\n",
+        )],
+    );
+    assert!(fixes.files.is_empty());
+    assert_eq!(
+        vec![
+            ("http_client", "Also declared with a valid name."),
+            ("fooBar", "Declared with different suggestions."),
+            ("fooBar", "Declared with different suggestions."),
+        ],
+        refused(&fixes)
+    );
+}
+
+#[test]
+fn rename_in_scope() {
+    let sources = [
+        ("a.py", Language::Python, "def getUserName():\n    pass\n"),
+        ("b.py", Language::Python, "from a import getUserName\n"),
+        ("c.py", Language::Python, "get_user_name = getUserName\n"),
+    ];
+
+    let fixes = fix(Scope::File, &sources);
+    let paths: Vec<&Path> =
+        fixes.files.iter().map(|file| file.path.as_path()).collect();
+    assert_eq!(vec![Path::new("a.py")], paths);
+
+    // Collides with a name in c.py.
+    let fixes = fix(Scope::Project, &sources);
+    assert!(fixes.files.is_empty());
+    assert_eq!(
+        vec![("getUserName", "Collides with an existing name.")],
+        refused(&fixes)
+    );
+
+    let fixes = fix(Scope::Project, &sources[..2]);
+    let fixed: Vec<&str> =
+        fixes.files.iter().map(|file| file.fixed.as_str()).collect();
+    assert_eq!(
+        vec![
+            "def get_user_name():\n    pass\n",
+            "from a import get_user_name\n",
+        ],
+        fixed
+    );
+}
+
+#[test]
+fn escape_keywords() {
+    let fixes = fix(
+        Scope::File,
+        &[(
+            "a.rs",
+            Language::Rust,
+            "fn Type() {}\nfn r#Match() { Type(); }\nfn main() { r#Match(); }\n",
+        )],
+    );
+    assert_eq!(
+        "fn r#type() {}\nfn r#match() { r#type(); }\nfn main() { r#match(); }\n",
+        fixes.files[0].fixed
+    );
+}
+
+#[test]
+fn diff_changed_lines() {
+    let original: String =
+        (1..=12).map(|i| format!("line {}\n", i)).collect::<String>() + "last";
+    let fixed = original
+        .replace("line 2\n", "LINE 2\n")
+        .replace("line 4\n", "LINE 4\n")
+        .replace("last", "LAST");
+    let fix = FileFix { path: PathBuf::from("src/a.rs"), original, fixed };
+
+    assert_eq!(
+        "\
+--- src/a.rs
++++ src/a.rs
+@@ -1,7 +1,7 @@
+ line 1
+-line 2
++LINE 2
+ line 3
+-line 4
++LINE 4
+ line 5
+ line 6
+ line 7
+@@ -10,4 +10,4 @@
+ line 10
+ line 11
+ line 12
+-last
+\\ No newline at end of file
++LAST
+\\ No newline at end of file
+",
+        fix.diff()
+    );
+
+    let same = FileFix {
+        path: PathBuf::from("a.rs"),
+        original: "a\n".to_string(),
+        fixed: "a\n".to_string(),
+    };
+    assert_eq!("", same.diff());
+}
+
+#[test]
+fn fix_source_trees() {
+    let tree = TempTree::new(
+        "tree",
+        &[
+            (
+                "src/a.go",
+                "package a\n\nfunc get_name() string {\n\treturn \"\"\n}\n",
+            ),
+            ("src/b.go", "package a\n\nvar x = get_name()\n"),
+            ("notes.txt", "get_name\n"),
+        ],
+    );
+
+    let mut fixer = Fixer::new(Scope::Project);
+    fixer.add_path(&Linter::default(), &tree.0).unwrap();
+    let fixes = fixer.fix();
+    assert_eq!(2, fixes.files.len());
+    for file in &fixes.files {
+        file.write().unwrap();
+    }
+
+    assert_eq!(
+        "package a\n\nvar x = getName()\n",
+        fs::read_to_string(tree.0.join("src/b.go")).unwrap()
+    );
+    assert_eq!(
+        "get_name\n",
+        fs::read_to_string(tree.0.join("notes.txt")).unwrap()
+    );
+    assert!(Linter::default().lint_path(&tree.0).unwrap().is_empty());
+}
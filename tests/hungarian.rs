@@ -0,0 +1,165 @@
+use naming_lib::hungarian::{
+    parse_scope, strip_prefix, Prefix, PrefixDictionary, ScopePrefix,
+};
+use naming_lib::{from, CaseKind};
+
+#[test]
+fn strip_known_systems_prefixes() {
+    let cases = [
+        ("szFileName", "sz", "FileName"),
+        ("lpszTitle", "lpsz", "Title"),
+        ("dwFlags", "dw", "Flags"),
+        ("bEnabled", "b", "Enabled"),
+        ("iCount", "i", "Count"),
+        ("nSize", "n", "Size"),
+        ("fnCallback", "fn", "Callback"),
+        ("pNext", "p", "Next"),
+        ("hWnd", "h", "Wnd"),
+    ];
+
+    for (identifier, prefix, rest) in cases {
+        let stripped = strip_prefix(identifier);
+        assert_eq!(prefix, stripped.prefix.unwrap().prefix, "{}", identifier);
+        assert_eq!(rest, stripped.name.as_str());
+    }
+}
+
+#[test]
+fn leave_unknown_prefixes_untouched() {
+    for identifier in ["isValid", "userName", "FileName", "file_name", "sz"] {
+        let stripped = strip_prefix(identifier);
+        assert_eq!(None, stripped.prefix);
+        assert_eq!(identifier, stripped.name.as_str());
+    }
+}
+
+#[test]
+fn classify_the_rest_of_identifier() {
+    assert_eq!(CaseKind::Pascal, strip_prefix("szFileName").name.kind());
+    assert_eq!(CaseKind::SingleWord, strip_prefix("dwFlags").name.kind());
+}
+
+#[test]
+fn use_apps_prefixes_or_configured_ones() {
+    let apps = PrefixDictionary::apps();
+    let stripped = apps.strip("cchName");
+    assert_eq!("count of characters", stripped.prefix.unwrap().meaning);
+    assert_eq!("index", apps.get("i").unwrap().meaning);
+
+    let mut dictionary = PrefixDictionary::empty();
+    assert_eq!(None, dictionary.strip("isValid").prefix);
+    dictionary.insert(Prefix::new("is", "boolean question"));
+    assert_eq!("Valid", dictionary.strip("isValid").name.as_str());
+
+    dictionary.insert(Prefix::new("is", "predicate"));
+    assert_eq!(1, dictionary.prefixes().len());
+    assert_eq!("predicate", dictionary.remove("is").unwrap().meaning);
+    assert!(dictionary.prefixes().is_empty());
+}
+
+#[test]
+fn generate_from_type_hints() {
+    let cases = [
+        ("file_name", "char*", "szFileName"),
+        ("file_name", "const char *", "szFileName"),
+        ("FLAGS", "DWORD", "dwFlags"),
+        ("isEnabled", "bool", "bIsEnabled"),
+        ("window", "HWND", "hWindow"),
+        ("item-count", "unsigned  int", "uItemCount"),
+    ];
+
+    for (identifier, type_hint, expected) in cases {
+        assert_eq!(expected, from(identifier).to_hungarian(type_hint).unwrap());
+    }
+
+    assert!(from("file_name").to_hungarian("Widget").is_err());
+    assert!(from("file name").to_hungarian("char*").is_err());
+}
+
+#[test]
+fn generated_identifiers_are_stripped_back() {
+    let generated = from("file_name").to_hungarian("char*").unwrap();
+    let stripped = strip_prefix(&generated);
+    assert_eq!("sz", stripped.prefix.unwrap().prefix);
+    assert_eq!("FileName", stripped.name.as_str());
+}
+
+#[test]
+fn generate_with_configured_type_hints() {
+    let mut dictionary = PrefixDictionary::empty();
+    dictionary.insert(
+        Prefix::new("v", "vector").with_type_hints(&["std::vector<int>"]),
+    );
+    assert_eq!(
+        "v",
+        dictionary.prefix_for_type("std::vector<int>").unwrap().prefix
+    );
+    assert_eq!(
+        "vItems",
+        from("items")
+            .to_hungarian_with("std::vector<int>", &dictionary)
+            .unwrap()
+    );
+    assert!(from("items").to_hungarian_with("char*", &dictionary).is_err());
+    assert!(PrefixDictionary::apps().prefix_for_type("int").is_none());
+}
+
+#[test]
+fn separate_scope_prefixes() {
+    let cases = [
+        ("m_userName", ScopePrefix::Member, "userName", CaseKind::Camel),
+        ("g_counter", ScopePrefix::Global, "counter", CaseKind::SingleWord),
+        ("s_instance", ScopePrefix::Static, "instance", CaseKind::SingleWord),
+        ("kMaxSize", ScopePrefix::Constant, "MaxSize", CaseKind::Pascal),
+        ("_user_name", ScopePrefix::Underscore, "user_name", CaseKind::Snake),
+    ];
+
+    for (identifier, scope, rest, kind) in cases {
+        let scoped = parse_scope(identifier);
+        assert_eq!(Some(scope), scoped.scope, "{}", identifier);
+        assert_eq!(rest, scoped.name.as_str());
+        assert_eq!(kind, scoped.name.kind());
+    }
+}
+
+#[test]
+fn not_scope_prefixes_without_valid_rest() {
+    for identifier in ["key", "kmax", "m_", "__init", "m_user name", "userName"]
+    {
+        let scoped = parse_scope(identifier);
+        assert_eq!(None, scoped.scope, "{}", identifier);
+        assert_eq!(identifier, scoped.name.as_str());
+    }
+}
+
+#[test]
+fn emit_with_other_scope_prefixes() {
+    let scoped = parse_scope("m_userName");
+    assert_eq!(
+        "g_userName",
+        scoped.with_scope(Some(ScopePrefix::Global)).unwrap()
+    );
+    assert_eq!(
+        "kUserName",
+        scoped.with_scope(Some(ScopePrefix::Constant)).unwrap()
+    );
+    assert_eq!("userName", scoped.with_scope(None).unwrap());
+
+    let constant = parse_scope("kMaxSize");
+    assert_eq!(
+        "s_MaxSize",
+        constant.with_scope(Some(ScopePrefix::Static)).unwrap()
+    );
+
+    assert!(parse_scope("a b")
+        .with_scope(Some(ScopePrefix::Constant))
+        .is_err());
+}
+
+#[test]
+fn strip_type_prefixes_after_scope_prefixes() {
+    let scoped = parse_scope("m_pszName");
+    let stripped = strip_prefix(scoped.name.as_str());
+    assert_eq!("psz", stripped.prefix.unwrap().prefix);
+    assert_eq!("Name", stripped.name.as_str());
+}
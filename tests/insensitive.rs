@@ -0,0 +1,34 @@
+use std::collections::HashSet;
+
+use naming_lib::CaseInsensitiveName;
+
+#[test]
+fn names_in_different_formats_are_equal() {
+    let names: HashSet<CaseInsensitiveName> =
+        ["userId", "user_id", "USER_ID", "user-id", "UserId"]
+            .iter()
+            .map(|name| CaseInsensitiveName::new(name))
+            .collect();
+    assert_eq!(1, names.len());
+}
+
+#[test]
+fn keep_the_given_identifier() {
+    let name = CaseInsensitiveName::new("userId");
+    assert_eq!("userId", name.to_string());
+    assert_eq!("userId", name.as_naming_case().as_str());
+    assert_eq!("userId", name.into_naming_case().as_str());
+}
+
+#[test]
+fn invalid_names_only_equal_to_identical_strings() {
+    let invalid = CaseInsensitiveName::new("user id");
+    assert_eq!(CaseInsensitiveName::new("user id"), invalid);
+    assert_ne!(CaseInsensitiveName::new("userId"), invalid);
+
+    // Not confused with the normalization key of a valid name.
+    assert_ne!(
+        CaseInsensitiveName::new("userId"),
+        CaseInsensitiveName::new("user.id")
+    );
+}
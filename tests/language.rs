@@ -0,0 +1,96 @@
+use naming_lib::language::{Escape, Language};
+use naming_lib::{from, TargetCase};
+
+#[test]
+fn find_keywords_of_each_language() {
+    let keywords = [
+        (Language::Rust, "type"),
+        (Language::Python, "def"),
+        (Language::Java, "class"),
+        (Language::Kotlin, "fun"),
+        (Language::Go, "func"),
+        (Language::JavaScript, "function"),
+        (Language::TypeScript, "enum"),
+        (Language::C, "typedef"),
+        (Language::Cpp, "template"),
+        (Language::CSharp, "namespace"),
+        (Language::Swift, "guard"),
+        (Language::Sql, "select"),
+    ];
+
+    for (language, keyword) in keywords {
+        assert!(language.is_keyword(keyword), "{} {}", language, keyword);
+        assert!(!language.is_keyword("user_name"), "{}", language);
+    }
+    assert!(Language::Sql.is_keyword("Order"));
+    assert!(!Language::Python.is_keyword("none"));
+    // Type names of TypeScript are legal identifiers.
+    for name in ["any", "boolean", "number", "string", "symbol"] {
+        assert!(!Language::TypeScript.is_keyword(name), "{}", name);
+        assert_eq!(name, Language::TypeScript.escape(name));
+    }
+}
+
+#[test]
+fn escape_keywords_by_default_of_each_language() {
+    assert_eq!("r#type", Language::Rust.escape("type"));
+    assert_eq!("self_", Language::Rust.escape("self"));
+    assert_eq!("def_", Language::Python.escape("def"));
+    assert_eq!("class_", Language::Java.escape("class"));
+    assert_eq!("`fun`", Language::Kotlin.escape("fun"));
+    assert_eq!("type_", Language::Go.escape("type"));
+    assert_eq!("@class", Language::CSharp.escape("class"));
+    assert_eq!("`func`", Language::Swift.escape("func"));
+    assert_eq!("\"ORDER\"", Language::Sql.escape("ORDER"));
+
+    // Not keywords.
+    assert_eq!("types", Language::Rust.escape("types"));
+}
+
+#[test]
+fn escape_keywords_in_chosen_way() {
+    let rust = Language::Rust;
+    assert_eq!("type_", rust.escape_with("type", Escape::TrailingUnderscore));
+    assert_eq!("r#type", rust.escape_with("type", Escape::RawIdentifier));
+    assert_eq!(
+        "\"user\"",
+        Language::Sql.escape_with("user", Escape::DoubleQuotes)
+    );
+}
+
+#[test]
+fn escape_after_conversion() {
+    let case = from("Type");
+    assert_eq!(
+        "r#type",
+        case.to_case_escaped(TargetCase::Snake, Language::Rust).unwrap()
+    );
+    assert_eq!(
+        "TYPE",
+        case.to_case_escaped(TargetCase::ScreamingSnake, Language::Rust)
+            .unwrap()
+    );
+    assert!(from("a b")
+        .to_case_escaped(TargetCase::Snake, Language::Rust)
+        .is_err());
+}
+
+#[test]
+fn parse_language_names() {
+    for language in Language::ALL {
+        assert_eq!(Ok(language), language.name().parse());
+    }
+    assert_eq!(Ok(Language::Cpp), "c++".parse());
+    assert!("cobol".parse::<Language>().is_err());
+}
+
+#[test]
+fn guess_languages_from_extensions() {
+    assert_eq!(Some(Language::Rust), Language::from_extension("rs"));
+    assert_eq!(Some(Language::TypeScript), Language::from_extension("tsx"));
+    assert_eq!(Some(Language::C), Language::from_extension("h"));
+    assert_eq!(Some(Language::Cpp), Language::from_extension("hpp"));
+    assert_eq!(Some(Language::Kotlin), Language::from_extension("kts"));
+    assert_eq!(None, Language::from_extension("md"));
+    assert_eq!(None, Language::from_extension("RS"));
+}
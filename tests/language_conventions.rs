@@ -0,0 +1,174 @@
+use naming_lib::language::{
+    check, check_with, expected_case, AcronymPolicy, CheckOptions, Convention,
+    Language, SymbolKind,
+};
+use naming_lib::{from, TargetCase};
+
+#[test]
+fn follow_style_guides_of_each_language() {
+    let cases = [
+        (Language::Rust, SymbolKind::Type, "HttpClient"),
+        (Language::Rust, SymbolKind::Static, "MAX_RETRIES"),
+        (Language::Rust, SymbolKind::Module, "http_client"),
+        (Language::Python, SymbolKind::Method, "__init__"),
+        (Language::Python, SymbolKind::Variable, "_cache"),
+        (Language::Java, SymbolKind::Method, "getName"),
+        (Language::Java, SymbolKind::EnumVariant, "NOT_FOUND"),
+        (Language::Kotlin, SymbolKind::Field, "userName"),
+        (Language::CSharp, SymbolKind::Method, "GetName"),
+        (Language::CSharp, SymbolKind::Parameter, "userName"),
+        (Language::Swift, SymbolKind::Constant, "maxRetries"),
+        (Language::TypeScript, SymbolKind::Constant, "MAX_RETRIES"),
+        (Language::JavaScript, SymbolKind::Function, "parseArgs"),
+        (Language::Go, SymbolKind::Function, "ParseArgs"),
+        (Language::Go, SymbolKind::Function, "parseArgs"),
+        (Language::Go, SymbolKind::Field, "userID"),
+    ];
+
+    for (language, kind, identifier) in cases {
+        assert_eq!(
+            Ok(()),
+            check(language, kind, identifier),
+            "{} {:?} {}",
+            language,
+            kind,
+            identifier
+        );
+    }
+}
+
+#[test]
+fn accept_words_of_digits() {
+    let cases = [
+        (Language::Rust, SymbolKind::Function, "sha_256"),
+        (Language::Rust, SymbolKind::Constant, "MAX_2"),
+        (Language::Rust, SymbolKind::Type, "Sha256"),
+        (Language::Python, SymbolKind::Variable, "utf_8_bytes"),
+        (Language::Java, SymbolKind::Method, "toUtf8"),
+        (Language::Kotlin, SymbolKind::EnumVariant, "HTTP_2"),
+    ];
+
+    for (language, kind, identifier) in cases {
+        assert_eq!(
+            Ok(()),
+            check(language, kind, identifier),
+            "{} {:?} {}",
+            language,
+            kind,
+            identifier
+        );
+    }
+
+    let violation =
+        check(Language::Rust, SymbolKind::Constant, "max_2").unwrap_err();
+    assert_eq!(Some("MAX_2".to_string()), violation.suggestion);
+}
+
+#[test]
+fn suggest_fixes_with_conversions() {
+    let cases = [
+        (Language::Rust, SymbolKind::Function, "parseArgs", "parse_args"),
+        (Language::Rust, SymbolKind::Constant, "maxRetries", "MAX_RETRIES"),
+        (Language::Rust, SymbolKind::Field, "Type", "r#type"),
+        (Language::Python, SymbolKind::Type, "http_client", "HttpClient"),
+        (Language::Python, SymbolKind::Function, "__parseArgs", "__parse_args"),
+        (Language::Java, SymbolKind::Field, "user_name", "userName"),
+        (Language::CSharp, SymbolKind::Method, "getName", "GetName"),
+        (Language::Go, SymbolKind::Function, "Parse_args", "ParseArgs"),
+        (Language::Go, SymbolKind::Function, "parse_args", "parseArgs"),
+        (Language::Rust, SymbolKind::Function, "parse$args", "parse_args"),
+    ];
+
+    for (language, kind, identifier, suggestion) in cases {
+        let violation = check(language, kind, identifier).unwrap_err();
+        assert_eq!(Some(suggestion.to_string()), violation.suggestion);
+    }
+}
+
+#[test]
+fn suggest_same_conversions_as_naming_case() {
+    let cases = [
+        (SymbolKind::Function, "parseArgs", TargetCase::Snake),
+        (SymbolKind::Function, "Abc123Def", TargetCase::Snake),
+        (SymbolKind::Type, "user-name", TargetCase::Pascal),
+        (SymbolKind::Constant, "max_retries", TargetCase::ScreamingSnake),
+        (SymbolKind::Function, "parse args", TargetCase::Snake),
+    ];
+
+    for (kind, identifier, target) in cases {
+        let case = from(identifier);
+        let expected =
+            case.to_case(target).or_else(|_| case.to_case_lossy(target));
+        let violation = check(Language::Rust, kind, identifier).unwrap_err();
+        assert_eq!(expected.ok(), violation.suggestion, "{}", identifier);
+    }
+}
+
+#[test]
+fn keep_acronyms_and_exceptions_on_top_of_conversions() {
+    let camel = Convention::Case(TargetCase::Camel);
+    let snake = Convention::Case(TargetCase::Snake);
+    let exceptions = ["iOS".to_string()];
+    let mut options = CheckOptions::default();
+    let suggest = |convention, identifier, options: &CheckOptions| {
+        check_with(Language::Java, convention, identifier, options)
+            .unwrap_err()
+            .suggestion
+            .unwrap()
+    };
+
+    assert!(check_with(Language::Java, camel, "userID", &options).is_ok());
+    assert_eq!(
+        "parse_http_request",
+        suggest(snake, "parseHTTPRequest", &options)
+    );
+    assert_eq!("httpServer", suggest(camel, "HTTPServer", &options));
+
+    options.acronyms = AcronymPolicy::Capitalize;
+    assert_eq!("userId", suggest(camel, "userID", &options));
+
+    options.exceptions = &exceptions;
+    assert_eq!("isiOSApp", suggest(camel, "is_iOS_app", &options));
+    assert_eq!("max_iOS_version", suggest(snake, "maxiOSVersion", &options));
+}
+
+#[test]
+fn no_suggestion_without_words() {
+    let violation =
+        check(Language::Rust, SymbolKind::Function, "$$").unwrap_err();
+    assert_eq!(TargetCase::Snake, violation.expected);
+    assert_eq!(None, violation.suggestion);
+}
+
+#[test]
+fn skip_kinds_without_conventions() {
+    assert_eq!(None, expected_case(Language::Java, SymbolKind::Module));
+    assert_eq!(None, expected_case(Language::C, SymbolKind::Function));
+    assert_eq!(Ok(()), check(Language::C, SymbolKind::Function, "whatEver"));
+    assert_eq!(
+        Some(Convention::GoVisibility),
+        expected_case(Language::Go, SymbolKind::Type)
+    );
+}
+
+#[test]
+fn parse_names_of_symbol_kinds() {
+    for kind in SymbolKind::ALL {
+        assert_eq!(Ok(kind), kind.name().parse());
+        assert_eq!(kind.name(), kind.to_string());
+    }
+    assert_eq!("enum-variant", SymbolKind::EnumVariant.name());
+    assert!("Function".parse::<SymbolKind>().is_err());
+}
+
+#[test]
+fn check_with_given_conventions() {
+    let kebab = Convention::Case(TargetCase::Kebab);
+    let options = CheckOptions::default();
+    assert!(check_with(Language::Rust, kebab, "foo-bar", &options).is_ok());
+
+    let violation =
+        check_with(Language::Rust, kebab, "fooBar", &options).unwrap_err();
+    assert_eq!(TargetCase::Kebab, violation.expected);
+    assert_eq!(Some("foo-bar".to_string()), violation.suggestion);
+}
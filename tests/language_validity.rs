@@ -0,0 +1,92 @@
+use naming_lib::language::{
+    is_go_exported, is_valid_identifier, sanitize_identifier, Language,
+    SQL_MAX_IDENTIFIER_LENGTH,
+};
+
+#[test]
+fn check_identifiers_by_language_rules() {
+    let rust = Language::Rust;
+    assert!(is_valid_identifier(rust, "_private"));
+    assert!(is_valid_identifier(rust, "変数"));
+    assert!(!is_valid_identifier(rust, "_"));
+    assert!(!is_valid_identifier(rust, "foo-bar"));
+    assert!(!is_valid_identifier(rust, "r#self"));
+
+    assert!(is_valid_identifier(Language::Java, "$proxy"));
+    assert!(is_valid_identifier(Language::TypeScript, "a$b"));
+    assert!(!is_valid_identifier(Language::Kotlin, "$proxy"));
+    assert!(is_valid_identifier(Language::Kotlin, "`fun`"));
+    assert!(is_valid_identifier(Language::CSharp, "@class"));
+    assert!(!is_valid_identifier(Language::Java, "class"));
+    assert!(is_valid_identifier(Language::Go, "Über"));
+    assert!(!is_valid_identifier(Language::C, "9lives"));
+}
+
+#[test]
+fn accept_single_underscore_where_not_reserved() {
+    let cases = [
+        (Language::Rust, false),
+        (Language::Java, false),
+        (Language::Kotlin, false),
+        (Language::Swift, false),
+        (Language::Python, true),
+        (Language::JavaScript, true),
+        (Language::TypeScript, true),
+        (Language::C, true),
+        (Language::Cpp, true),
+        (Language::Go, true),
+        (Language::CSharp, true),
+        (Language::Sql, true),
+    ];
+
+    for (language, valid) in cases {
+        assert_eq!(valid, is_valid_identifier(language, "_"), "{}", language);
+    }
+}
+
+#[test]
+fn limit_sql_identifiers() {
+    let sql = Language::Sql;
+    assert!(is_valid_identifier(sql, "user_id"));
+    assert!(!is_valid_identifier(sql, "order"));
+    assert!(is_valid_identifier(sql, "\"order\""));
+    assert!(is_valid_identifier(sql, "\"say \"\"hi\"\"\""));
+    assert!(!is_valid_identifier(sql, "\"a\"b\""));
+    assert!(!is_valid_identifier(sql, "naïve"));
+
+    let long = "a".repeat(SQL_MAX_IDENTIFIER_LENGTH + 1);
+    assert!(!is_valid_identifier(sql, &long));
+    assert_eq!(
+        SQL_MAX_IDENTIFIER_LENGTH,
+        sanitize_identifier(sql, &long).unwrap().len()
+    );
+}
+
+#[test]
+fn sanitize_to_legal_identifiers() {
+    let cases = [
+        (Language::Rust, "user name", "user_name"),
+        (Language::Python, "class", "class_"),
+        (Language::Go, "http-server", "httpServer"),
+        (Language::Kotlin, "Fun", "Fun"),
+        (Language::Kotlin, "fun!", "`fun`"),
+        (Language::CSharp, "class?", "@class"),
+        (Language::Sql, "select", "\"select\""),
+        (Language::C, "3d point", "_3_d_point"),
+    ];
+
+    for (language, raw, expected) in cases {
+        let sanitized = sanitize_identifier(language, raw).unwrap();
+        assert_eq!(expected, sanitized, "{} {}", language, raw);
+        assert!(is_valid_identifier(language, &sanitized));
+    }
+    assert!(sanitize_identifier(Language::Rust, "@@").is_err());
+}
+
+#[test]
+fn find_exported_go_identifiers() {
+    assert!(is_go_exported("Reader"));
+    assert!(is_go_exported("Éclair"));
+    assert!(!is_go_exported("reader"));
+    assert!(!is_go_exported(""));
+}
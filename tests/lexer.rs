@@ -0,0 +1,436 @@
+#[cfg(test)]
+#[macro_use(quickcheck)]
+extern crate quickcheck_macros;
+
+use naming_lib::language::{Language, SymbolKind};
+use naming_lib::lexer::{Lexer, Span};
+
+use SymbolKind::*;
+
+fn identifiers(language: Language, source: &str) -> Vec<&str> {
+    Lexer::new(language, source).map(|token| token.text).collect()
+}
+
+fn declarations(language: Language, source: &str) -> Vec<(&str, SymbolKind)> {
+    Lexer::new(language, source)
+        .filter_map(|token| Some((token.text, token.kind?)))
+        .collect()
+}
+
+#[test]
+fn skip_comments() {
+    assert_eq!(
+        vec!["a", "b", "c"],
+        identifiers(Language::Rust, "a // x\n/* y /* z */ w */ b /** v */ c")
+    );
+    assert_eq!(
+        vec!["a", "w", "b"],
+        identifiers(Language::C, "a /* y /* z */ w */ b")
+    );
+    // Floor division in Python.
+    assert_eq!(
+        vec!["a", "b", "c"],
+        identifiers(Language::Python, "a # x\nb // c")
+    );
+    assert_eq!(vec!["a", "b"], identifiers(Language::Sql, "a -- x\n/* y */ b"));
+}
+
+#[test]
+fn skip_strings() {
+    let cases = [
+        (Language::Rust, r#"a "b \" c" d 'e' f '\'' g"#),
+        (Language::Rust, r###"a r#"b " c"# d b"e" f br##"g"## g"###),
+        (Language::Python, r#"a 'b' """c " d""" f"e" rb'f' g"#),
+        (Language::Go, "a `b\nc` d 'e' f"),
+        (Language::JavaScript, "a `b ${c}` d 'e' f"),
+        (Language::Cpp, r#"a R"x(b )" c)x" d u8"e" f 'g' g"#),
+        (Language::CSharp, r#"a @"b "" c" d $"e" f"#),
+        (Language::Sql, "a 'b '' c' d \"E\" f"),
+        (Language::Kotlin, "a \"\"\"b \" c\"\"\" d `e f` g"),
+    ];
+    for (language, source) in cases.iter() {
+        let found = identifiers(*language, source);
+        assert_eq!(Some(&"a"), found.first(), "{}", source);
+        assert!(
+            found.iter().all(|word| ["a", "d", "f", "g"].contains(word)),
+            "{:?} in {}",
+            found,
+            source
+        );
+    }
+}
+
+#[test]
+fn skip_keywords_and_numbers() {
+    assert_eq!(
+        vec!["x", "y"],
+        identifiers(Language::Rust, "if x == 0x1Fu32 { return y.0; }")
+    );
+    assert_eq!(
+        vec!["name", "Users"],
+        identifiers(Language::Sql, "SELECT name From Users where 1")
+    );
+    // Rust raw identifiers and C# verbatim identifiers.
+    assert_eq!(vec!["type"], identifiers(Language::Rust, "let r#type;"));
+    assert_eq!(vec!["class"], identifiers(Language::CSharp, "@class"));
+    // Lifetimes and labels.
+    assert_eq!(
+        vec!["T", "x", "T"],
+        identifiers(Language::Rust, "impl<'a, T> for<'b> 'outer: x: &'a T")
+    );
+}
+
+#[test]
+fn spans_of_tokens() {
+    let source = "let a = 1;\n  // é\n  let été = \"ü\"; b";
+    let spans: Vec<(&str, Span)> = Lexer::new(Language::Rust, source)
+        .map(|token| (token.text, token.span))
+        .collect();
+
+    assert_eq!(
+        vec![
+            ("a", Span { start: 4, end: 5, line: 1, column: 5 }),
+            ("été", Span { start: 25, end: 30, line: 3, column: 7 }),
+            ("b", Span { start: 39, end: 40, line: 3, column: 18 }),
+        ],
+        spans
+    );
+    for (text, span) in spans {
+        assert_eq!(text, &source[span.start..span.end]);
+    }
+}
+
+#[test]
+fn declarations_of_rust() {
+    let source = "\
+mod net;
+const MAX: u32 = 1;
+static mut COUNT: u32 = 0;
+pub struct Client<T> { pub name: String, inner: T }
+enum Color { Red, Green(u8), Blue { x: u8 } }
+trait Send { fn send(&self); }
+impl Client { fn new(name: String) -> Self { let mut result = x; } }
+if let Some(found) = x {} else if let Ok(Kind::A) = y {}
+type Id = u64;
+fn main() -> impl Iterator<Item = u8> { fn helper() {} }
+";
+    assert_eq!(
+        vec![
+            ("net", Module),
+            ("MAX", Constant),
+            ("COUNT", Static),
+            ("Client", Type),
+            ("name", Field),
+            ("inner", Field),
+            ("Color", Type),
+            ("Red", EnumVariant),
+            ("Green", EnumVariant),
+            ("Blue", EnumVariant),
+            ("Send", Type),
+            ("send", Method),
+            ("new", Method),
+            ("name", Parameter),
+            ("result", Variable),
+            ("Id", Type),
+            ("main", Function),
+            ("helper", Function),
+        ],
+        declarations(Language::Rust, source)
+    );
+}
+
+#[test]
+fn declarations_of_python() {
+    let source = "\
+class UserAccount(Base):
+    def __init__(self, name, age=default):
+        self.name = name
+
+async def fetch_all(url): pass
+";
+    assert_eq!(
+        vec![
+            ("UserAccount", Type),
+            ("__init__", Method),
+            ("self", Parameter),
+            ("name", Parameter),
+            ("age", Parameter),
+            ("fetch_all", Function),
+            ("url", Parameter),
+        ],
+        declarations(Language::Python, source)
+    );
+}
+
+#[test]
+fn declarations_of_java_and_csharp() {
+    let source = "\
+package com.example;
+public class Account {
+    public static final int MAX_SIZE = 10;
+    private String ownerName;
+    public void setOwner(String name, int[] ids) {
+        int count = 0;
+    }
+}
+enum Color { RED, GREEN; private int code; }
+";
+    assert_eq!(
+        vec![
+            ("Account", Type),
+            ("MAX_SIZE", Constant),
+            ("ownerName", Field),
+            ("setOwner", Method),
+            ("name", Parameter),
+            ("ids", Parameter),
+            ("count", Variable),
+            ("Color", Type),
+            ("RED", EnumVariant),
+            ("GREEN", EnumVariant),
+            ("code", Field),
+        ],
+        declarations(Language::Java, source)
+    );
+
+    let source = "\
+namespace App {
+    class Service {
+        const int Retries = 3;
+        public string Name { get; set; }
+        public void Run(int times) {}
+    }
+}
+";
+    assert_eq!(
+        vec![
+            ("App", Module),
+            ("Service", Type),
+            ("Retries", Constant),
+            ("Run", Method),
+            ("times", Parameter),
+        ],
+        declarations(Language::CSharp, source)
+    );
+}
+
+#[test]
+fn declarations_of_go() {
+    let source = "\
+package main
+
+const (
+\tMaxRetries = 3
+\tminDelay
+)
+
+type Server struct {
+\tAddr string
+\tport int
+}
+
+type Handler interface {
+\tServe(w Writer) error
+}
+
+func (s *Server) Start(addr string) error {
+\tcount := 0
+\tgo func(x int) {}(1)
+}
+
+func main() {}
+";
+    assert_eq!(
+        vec![
+            ("main", Module),
+            ("MaxRetries", Constant),
+            ("minDelay", Constant),
+            ("Server", Type),
+            ("Addr", Field),
+            ("port", Field),
+            ("Handler", Type),
+            ("Serve", Method),
+            ("w", Parameter),
+            ("Start", Method),
+            ("addr", Parameter),
+            ("count", Variable),
+            ("main", Function),
+        ],
+        declarations(Language::Go, source)
+    );
+}
+
+#[test]
+fn declarations_of_javascript_and_typescript() {
+    let source = "\
+import { readFile } from 'fs';
+const MAX = 1, other = 2;
+let { a, b } = obj;
+function* walk(node) {}
+class Tree extends Base {
+  size = 0;
+  constructor(root) {}
+  static from(items) {}
+}
+";
+    assert_eq!(
+        vec![
+            ("MAX", Variable),
+            ("walk", Function),
+            ("node", Parameter),
+            ("Tree", Type),
+            ("size", Field),
+            ("constructor", Method),
+            ("root", Parameter),
+            ("from", Method),
+            ("items", Parameter),
+        ],
+        declarations(Language::JavaScript, source)
+    );
+
+    let source = "\
+interface Shape {
+  area(): number;
+  name?: string;
+}
+type Point = { x: number };
+enum Direction { Up, Down }
+let type: string;
+";
+    assert_eq!(
+        vec![
+            ("Shape", Type),
+            ("area", Method),
+            ("name", Field),
+            ("Point", Type),
+            ("Direction", Type),
+            ("Up", EnumVariant),
+            ("Down", EnumVariant),
+            ("type", Variable),
+        ],
+        declarations(Language::TypeScript, source)
+    );
+}
+
+#[test]
+fn declarations_of_c_and_cpp() {
+    let source = "\
+#include <stdio.h>
+#define BUFFER_SIZE 64
+struct point { int x; int y; };
+struct point origin;
+static int count_items(const char *name, int limit) {
+    int total = 0;
+}
+";
+    assert_eq!(
+        vec![
+            ("BUFFER_SIZE", Constant),
+            ("point", Type),
+            ("x", Field),
+            ("y", Field),
+            ("origin", Variable),
+            ("count_items", Function),
+            ("limit", Parameter),
+            ("total", Variable),
+        ],
+        declarations(Language::C, source)
+    );
+
+    let source = "\
+namespace net {
+class Socket : public Base {
+    int fd;
+    void close();
+};
+enum class State { Open, Closed };
+}
+";
+    assert_eq!(
+        vec![
+            ("net", Module),
+            ("Socket", Type),
+            ("fd", Field),
+            ("close", Method),
+            ("State", Type),
+            ("Open", EnumVariant),
+            ("Closed", EnumVariant),
+        ],
+        declarations(Language::Cpp, source)
+    );
+}
+
+#[test]
+fn declarations_of_sql() {
+    let source = "\
+CREATE TABLE IF NOT EXISTS user_accounts (
+    id INTEGER PRIMARY KEY,
+    display_name TEXT NOT NULL
+);
+create view active_users as select id from user_accounts;
+CREATE INDEX idx_name ON user_accounts (display_name);
+";
+    assert_eq!(
+        vec![
+            ("user_accounts", Type),
+            ("id", Field),
+            ("display_name", Field),
+            ("active_users", Type),
+        ],
+        declarations(Language::Sql, source)
+    );
+}
+
+#[test]
+fn declarations_of_kotlin_and_swift() {
+    let source = "\
+data class User(val name: String) {
+    fun greet(other: User) {}
+}
+const val MAX_USERS = 10
+fun String.shout() = this
+";
+    assert_eq!(
+        vec![
+            ("User", Type),
+            ("name", Variable),
+            ("greet", Method),
+            ("other", Parameter),
+            ("MAX_USERS", Constant),
+            ("shout", Function),
+        ],
+        declarations(Language::Kotlin, source)
+    );
+
+    let source = "\
+enum Direction { case north, south }
+struct Point { var x: Int; func moved() -> Point { let copy = self } }
+";
+    assert_eq!(
+        vec![
+            ("Direction", Type),
+            ("north", EnumVariant),
+            ("south", EnumVariant),
+            ("Point", Type),
+            ("x", Field),
+            ("moved", Method),
+            ("copy", Variable),
+        ],
+        declarations(Language::Swift, source)
+    );
+}
+
+#[quickcheck]
+fn spans_are_in_order_and_cover_tokens(words: Vec<String>) -> bool {
+    // Mix in syntax so random strings hit more paths.
+    let source = words.join(" \"'`/*#{(@r$.\n");
+    Language::ALL.iter().all(|language| {
+        let mut last_end = 0;
+        Lexer::new(*language, &source).all(|token| {
+            let ok = token.span.start >= last_end
+                && source[token.span.start..token.span.end]
+                    .ends_with(token.text);
+            last_end = token.span.end;
+            ok
+        })
+    })
+}
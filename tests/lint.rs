@@ -0,0 +1,272 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use naming_lib::language::{check, Convention, Language, SymbolKind};
+use naming_lib::lint::{AcronymPolicy, Linter, Preset, Rules};
+use naming_lib::{CaseKind, TargetCase};
+
+/// A directory of source files, removed on drop.
+struct TempTree(PathBuf);
+
+impl TempTree {
+    fn new(name: &str, files: &[(&str, &str)]) -> TempTree {
+        let root = std::env::temp_dir().join(format!(
+            "naming_lib-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        for (path, content) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        TempTree(root)
+    }
+}
+
+impl Drop for TempTree {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn lint(language: Language, source: &str) -> Vec<(String, SymbolKind)> {
+    Linter::default()
+        .lint_source(Path::new("src"), language, source)
+        .into_iter()
+        .map(|diagnostic| (diagnostic.identifier, diagnostic.kind))
+        .collect()
+}
+
+#[test]
+fn report_violations_with_locations() {
+    let source = "\
+// fn ignoredInComment() {}
+struct http_client {
+    retries: u32,
+}
+
+const maxRetries: u32 = 3;
+fn sendRequest() {
+    let RetryCount = 0;
+}
+";
+    let diagnostics = Linter::default().lint_source(
+        Path::new("lib.rs"),
+        Language::Rust,
+        source,
+    );
+    let found: Vec<(&str, usize, usize, CaseKind, TargetCase)> = diagnostics
+        .iter()
+        .map(|d| {
+            (
+                d.identifier.as_str(),
+                d.location.line,
+                d.location.column,
+                d.detected,
+                d.expected,
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        vec![
+            ("http_client", 2, 8, CaseKind::Snake, TargetCase::Pascal),
+            ("maxRetries", 6, 7, CaseKind::Camel, TargetCase::ScreamingSnake),
+            ("sendRequest", 7, 4, CaseKind::Camel, TargetCase::Snake),
+            ("RetryCount", 8, 9, CaseKind::Pascal, TargetCase::Snake),
+        ],
+        found
+    );
+    assert_eq!(Some("HttpClient".to_string()), diagnostics[0].suggestion);
+}
+
+#[test]
+fn follow_conventions_of_each_language() {
+    assert_eq!(
+        vec![("getName".to_string(), SymbolKind::Method)],
+        lint(Language::Python, "class User:\n    def getName(self): pass\n")
+    );
+    assert_eq!(
+        vec![("user_name".to_string(), SymbolKind::Variable)],
+        lint(Language::JavaScript, "let user_name = 1;\nclass Foo {}\n")
+    );
+    assert_eq!(
+        vec![("parse_args".to_string(), SymbolKind::Function)],
+        lint(Language::Go, "func ParseArgs() {}\nfunc parse_args() {}\n")
+    );
+    assert_eq!(
+        vec![("Max".to_string(), SymbolKind::Constant)],
+        lint(Language::Kotlin, "const val Max = 1\nval userName = 2\n")
+    );
+    // No conventions for C.
+    assert!(lint(Language::C, "struct fooBar {};\n").is_empty());
+}
+
+#[test]
+fn override_conventions_of_preset() {
+    let mut preset = Preset::standard();
+    preset.set(
+        Language::C,
+        SymbolKind::Type,
+        Some(Convention::Case(TargetCase::Pascal)),
+    );
+    preset.set(Language::Rust, SymbolKind::Function, None);
+    assert_eq!(None, preset.convention(Language::Rust, SymbolKind::Function));
+
+    let linter = Linter::new(preset);
+    let path = Path::new("a");
+    assert_eq!(
+        1,
+        linter.lint_source(path, Language::C, "struct foo_bar {};").len()
+    );
+    assert!(linter
+        .lint_source(path, Language::Rust, "fn fooBar() {}")
+        .is_empty());
+}
+
+#[test]
+fn walk_source_trees() {
+    let tree = TempTree::new(
+        "walk",
+        &[
+            ("src/main.rs", "fn mainLoop() {}\n"),
+            ("src/util/mod.rs", "struct buffer;\n"),
+            ("scripts/run.py", "def runAll(): pass\n"),
+            ("README.md", "fn notCode() {}\n"),
+            (".git/hooks/x.rs", "fn hidden() {}\nfn notChecked() {}\n"),
+        ],
+    );
+
+    let diagnostics = Linter::default().lint_path(&tree.0).unwrap();
+    let found: Vec<(PathBuf, &str)> = diagnostics
+        .iter()
+        .map(|d| {
+            (
+                d.location.path.strip_prefix(&tree.0).unwrap().to_path_buf(),
+                d.identifier.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            (PathBuf::from("scripts/run.py"), "runAll"),
+            (PathBuf::from("src/main.rs"), "mainLoop"),
+            (PathBuf::from("src/util/mod.rs"), "buffer"),
+        ],
+        found
+    );
+
+    let single = Linter::default().lint_path(&tree.0.join("src/main.rs"));
+    assert_eq!(1, single.unwrap().len());
+    assert!(Linter::default().lint_path(&tree.0.join("missing")).is_err());
+}
+
+#[test]
+fn keep_acronyms_and_exceptions_as_words() {
+    let mut rules = Rules::standard();
+    let check = |rules: &Rules, kind, identifier| {
+        rules
+            .check(Language::Java, kind, identifier)
+            .map_err(|violation| violation.suggestion.unwrap())
+    };
+
+    assert_eq!(Ok(()), check(&rules, SymbolKind::Type, "HTTPServer"));
+    assert_eq!(Ok(()), check(&rules, SymbolKind::Type, "HttpServer"));
+    assert_eq!(Ok(()), check(&rules, SymbolKind::Variable, "userID"));
+    assert_eq!(Ok(()), check(&rules, SymbolKind::Variable, "utf8Bytes"));
+    assert_eq!(
+        Err("MAX_HTTP_RETRIES".to_string()),
+        check(&rules, SymbolKind::Constant, "maxHTTPRetries")
+    );
+    assert_eq!(
+        Err("httpServer".to_string()),
+        check(&rules, SymbolKind::Variable, "HTTPServer")
+    );
+    // A whole identifier in uppercase isn't an acronym.
+    assert_eq!(
+        Err("maxSize".to_string()),
+        check(&rules, SymbolKind::Variable, "MAX_SIZE")
+    );
+
+    rules.acronyms = AcronymPolicy::Capitalize;
+    assert_eq!(
+        Err("HttpServer".to_string()),
+        check(&rules, SymbolKind::Type, "HTTPServer")
+    );
+    assert_eq!(
+        Err("userId".to_string()),
+        check(&rules, SymbolKind::Variable, "userID")
+    );
+    assert_eq!(
+        Err("macOsVersion".to_string()),
+        check(&rules, SymbolKind::Variable, "macOSVersion")
+    );
+
+    rules.exceptions = vec!["macOS".to_string(), "iOS".to_string()];
+    assert_eq!(Ok(()), check(&rules, SymbolKind::Variable, "macOSVersion"));
+    assert_eq!(Ok(()), check(&rules, SymbolKind::Type, "AppForiOS"));
+    assert_eq!(Ok(()), check(&rules, SymbolKind::Variable, "iOS"));
+    assert_eq!(
+        Err("iOSVersion".to_string()),
+        check(&rules, SymbolKind::Variable, "iOS_version")
+    );
+    assert_eq!(
+        Err("MAX_iOS_VERSION".to_string()),
+        check(&rules, SymbolKind::Constant, "maxiOSVersion")
+    );
+}
+
+#[test]
+fn agree_with_language_check() {
+    let rules = Rules::standard();
+    let cases = [
+        (Language::Rust, SymbolKind::Function, "sha_256"),
+        (Language::Rust, SymbolKind::Constant, "MAX_2"),
+        (Language::Rust, SymbolKind::Function, "parseHTTPRequest"),
+        (Language::Java, SymbolKind::Type, "HTTPServer"),
+        (Language::Python, SymbolKind::Function, "__parseArgs"),
+        (Language::Rust, SymbolKind::Field, "Type"),
+        (Language::Go, SymbolKind::Field, "userID"),
+        (Language::Rust, SymbolKind::Function, "$$"),
+    ];
+
+    for (language, kind, identifier) in cases {
+        assert_eq!(
+            check(language, kind, identifier),
+            rules.check(language, kind, identifier),
+            "{} {:?} {}",
+            language,
+            kind,
+            identifier
+        );
+    }
+}
+
+#[test]
+fn skip_ignored_paths() {
+    let tree = TempTree::new(
+        "ignore",
+        &[
+            ("src/main.rs", "fn mainLoop() {}\n"),
+            ("src/generated/api.rs", "fn getUser() {}\n"),
+            ("src/api.pb.rs", "fn getUser() {}\n"),
+            ("vendor/lib/x.rs", "fn vendored() {}\nfn notMine() {}\n"),
+            ("lib/vendor/x.rs", "fn alsoNotMine() {}\n"),
+        ],
+    );
+    let root = fs::canonicalize(&tree.0).unwrap();
+    let mut rules = Rules::standard();
+    for pattern in ["src/generated/", "*.pb.rs", "vendor"] {
+        rules.add_ignore(&root, pattern).unwrap();
+    }
+    assert!(rules.add_ignore(&root, "/").is_err());
+    assert!(rules.is_ignored(&root.join("src/generated/api.rs")));
+    assert!(!rules.is_ignored(&root.join("generated/api.rs")));
+
+    let diagnostics = Linter::with_rules(rules).lint_path(&tree.0).unwrap();
+    let found: Vec<&str> =
+        diagnostics.iter().map(|d| d.identifier.as_str()).collect();
+    assert_eq!(vec!["mainLoop"], found);
+}
@@ -0,0 +1,264 @@
+#![cfg(feature = "toml")]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use naming_lib::language::{Convention, Language, SymbolKind};
+use naming_lib::lint::{AcronymPolicy, Config, Linter, Rules};
+use naming_lib::TargetCase;
+
+/// A directory of files, removed on drop.
+struct TempTree(PathBuf);
+
+impl TempTree {
+    fn new(name: &str, files: &[(&str, &str)]) -> TempTree {
+        let root = std::env::temp_dir().join(format!(
+            "naming_lib-config-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        for (path, content) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        TempTree(root)
+    }
+}
+
+impl Drop for TempTree {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn found(linter: &Linter, path: &Path) -> Vec<(String, String)> {
+    linter
+        .lint_path(path)
+        .unwrap()
+        .into_iter()
+        .map(|d| {
+            let file = d.location.path.file_name().unwrap().to_string_lossy();
+            (file.to_string(), d.identifier)
+        })
+        .collect()
+}
+
+#[test]
+fn parse_every_setting() {
+    let config = Config::parse(
+        r#"
+root = true
+acronyms = "capitalize"
+exceptions = ["iOS", "macOS"]
+ignore = ["target/", "*.pb.go"]
+
+[rust]
+function = "camel"
+field = "none"
+
+["c++"]
+type = "pascal"
+
+[go]
+variable = "go-visibility"
+"#,
+    )
+    .unwrap();
+
+    assert!(config.root);
+    assert_eq!(Some(AcronymPolicy::Capitalize), config.acronyms);
+    assert_eq!(vec!["iOS", "macOS"], config.exceptions);
+    assert_eq!(vec!["target/", "*.pb.go"], config.ignore);
+    // Tables and keys are sorted.
+    assert_eq!(
+        vec![
+            (
+                Language::Cpp,
+                SymbolKind::Type,
+                Some(Convention::Case(TargetCase::Pascal))
+            ),
+            (
+                Language::Go,
+                SymbolKind::Variable,
+                Some(Convention::GoVisibility)
+            ),
+            (Language::Rust, SymbolKind::Field, None),
+            (
+                Language::Rust,
+                SymbolKind::Function,
+                Some(Convention::Case(TargetCase::Camel))
+            ),
+        ],
+        config.conventions
+    );
+    assert_eq!(Config::default(), Config::parse("").unwrap());
+}
+
+#[test]
+fn point_errors_at_bad_keys() {
+    let cases = [
+        ("colour = 1", Some("colour"), "Unknown key."),
+        ("root = \"yes\"", Some("root"), "Expected a boolean."),
+        (
+            "acronyms = \"keep\"",
+            Some("acronyms"),
+            "Unknown acronym policy name.",
+        ),
+        (
+            "exceptions = \"iOS\"",
+            Some("exceptions"),
+            "Expected an array of strings.",
+        ),
+        (
+            "exceptions = [\"iOS\", 1]",
+            Some("exceptions[1]"),
+            "Expected a string.",
+        ),
+        ("exceptions = [\"\"]", Some("exceptions"), "Empty exception."),
+        ("ignore = [\"a\", \"/\"]", Some("ignore[1]"), "Empty ignore pattern."),
+        ("rust = \"snake\"", Some("rust"), "Expected a table."),
+        (
+            "[rust]\nfunc = \"snake\"",
+            Some("rust.func"),
+            "Unknown symbol kind name.",
+        ),
+        (
+            "[rust]\nfunction = \"snek\"",
+            Some("rust.function"),
+            "Unknown convention name.",
+        ),
+        (
+            "[rust]\nfunction = true",
+            Some("rust.function"),
+            "Expected a string.",
+        ),
+        (
+            "[\"c++\"]\ntype = \"x\"",
+            Some("\"c++\".type"),
+            "Unknown convention name.",
+        ),
+    ];
+    for (content, key, message) in cases.iter() {
+        let error = Config::parse(content).unwrap_err();
+        assert_eq!(key.map(str::to_string), error.key, "{}", content);
+        assert_eq!(*message, error.message, "{}", content);
+        assert_eq!(None, error.path);
+    }
+
+    let error = Config::parse("[rust\nfunction = 1").unwrap_err();
+    assert_eq!(None, error.key);
+    assert!(!error.message.is_empty());
+}
+
+#[test]
+fn apply_on_inherited_rules() {
+    let mut rules = Rules::standard();
+    rules.exceptions.push("iOS".to_string());
+    Config::parse("exceptions = [\"macOS\"]\n[rust]\nfunction = \"camel\"")
+        .unwrap()
+        .apply(&mut rules, Path::new("/project"))
+        .unwrap();
+
+    assert_eq!(vec!["iOS", "macOS"], rules.exceptions);
+    assert_eq!(AcronymPolicy::Preserve, rules.acronyms);
+    assert!(rules
+        .check(Language::Rust, SymbolKind::Function, "parseArgs")
+        .is_ok());
+
+    Config::parse("root = true")
+        .unwrap()
+        .apply(&mut rules, Path::new("/"))
+        .unwrap();
+    assert!(rules.exceptions.is_empty());
+    assert!(rules
+        .check(Language::Rust, SymbolKind::Function, "parseArgs")
+        .is_err());
+
+    // Configs built in code aren't checked while parsing.
+    let config = Config {
+        root: true,
+        ignore: vec!["target".to_string(), "/".to_string()],
+        ..Config::default()
+    };
+    let error = config.apply(&mut rules, Path::new("/")).unwrap_err();
+    assert_eq!(Some("ignore[1]".to_string()), error.key);
+    assert!(!rules.is_ignored(Path::new("/target")));
+}
+
+#[test]
+fn override_rules_per_directory() {
+    let tree = TempTree::new(
+        "nested",
+        &[
+            (".naming.toml", "exceptions = [\"iOS\"]\nignore = [\"gen\"]\n"),
+            ("a.rs", "fn is_iOS() {}\nfn parseArgs() {}\n"),
+            ("gen/b.rs", "fn parseArgs() {}\n"),
+            ("legacy/.naming.toml", "[rust]\nfunction = \"camel\"\n"),
+            ("legacy/c.rs", "fn parseArgs() {}\nfn is_iOS() {}\n"),
+            ("legacy/old/d.rs", "fn parseArgs() {}\n"),
+            ("legacy/old/gen/e.rs", "fn parseArgs() {}\n"),
+            ("strict/.naming.toml", "root = true\n"),
+            ("strict/f.rs", "fn is_iOS() {}\n"),
+        ],
+    );
+
+    assert_eq!(
+        vec![
+            ("a.rs".to_string(), "parseArgs".to_string()),
+            ("c.rs".to_string(), "is_iOS".to_string()),
+            ("f.rs".to_string(), "is_iOS".to_string()),
+        ],
+        found(&Linter::default(), &tree.0)
+    );
+}
+
+#[test]
+fn discover_configs_of_parent_directories() {
+    let tree = TempTree::new(
+        "discover",
+        &[
+            (".naming.toml", "[rust]\nfunction = \"camel\"\n"),
+            ("src/.naming.toml", "acronyms = \"capitalize\"\n"),
+            ("src/a.rs", "fn parseHTTP() {}\nfn parse_args() {}\n"),
+            ("inner/.naming.toml", "root = true\n"),
+            ("inner/src/b.rs", "fn parseArgs() {}\n"),
+        ],
+    );
+
+    let src = tree.0.join("src");
+    let linter = Linter::discover(&src).unwrap();
+    assert_eq!(
+        vec![
+            ("a.rs".to_string(), "parseHTTP".to_string()),
+            ("a.rs".to_string(), "parse_args".to_string()),
+        ],
+        found(&linter, &src)
+    );
+    // A file gets the config of its own directory.
+    let file = src.join("a.rs");
+    assert_eq!(2, found(&Linter::discover(&file).unwrap(), &file).len());
+
+    let inner = tree.0.join("inner/src");
+    assert_eq!(1, found(&Linter::discover(&inner).unwrap(), &inner).len());
+    assert!(Linter::discover(&tree.0.join("missing")).is_err());
+}
+
+#[test]
+fn report_bad_config_files() {
+    let tree = TempTree::new(
+        "bad",
+        &[("sub/.naming.toml", "[rust]\nfunction = 1\n"), ("sub/a.rs", "")],
+    );
+
+    let error = Linter::default().lint_path(&tree.0).unwrap_err();
+    let message = error.to_string();
+    assert!(message.ends_with("`rust.function`: Expected a string."));
+    assert!(message.contains(".naming.toml"));
+
+    let error = Linter::discover(&tree.0.join("sub/a.rs")).unwrap_err();
+    assert_eq!(Some("rust.function".to_string()), error.key);
+    let root = fs::canonicalize(&tree.0).unwrap();
+    assert_eq!(Some(root.join("sub/.naming.toml")), error.path);
+}
@@ -8,7 +8,7 @@ use quickcheck::{quickcheck, TestResult};
 
 use common::*;
 use naming_lib as lib;
-use naming_lib::NamingCase;
+use naming_lib::{CaseKind, NamingCase, TargetCase};
 
 mod common;
 
@@ -25,30 +25,48 @@ fn recognise_camel_case_as_hungarian_notation_and_others_as_invalid(
         if lib::is_camel(&s) {
             TestResult::discard()
         } else {
-            TestResult::from_bool(
-                lib::from_hungarian_notation(&s) == NamingCase::Invalid(s),
-            )
+            TestResult::from_bool(lib::from_hungarian_notation(&s).is_err())
         }
     } else {
         // We can use a valid single word to generate different format strings.
         // That's why we detect randomly generated string's format first above.
-        let judged_cases = build_all_format_str(s)
+        let strs = build_all_format_str(s);
+        let judged_cases = strs
             .iter()
             .map(|s| lib::from_hungarian_notation(s))
-            .collect::<Vec<NamingCase>>();
+            .collect::<Vec<Result<NamingCase, _>>>();
 
-        // Can't directly use "NamingCase::Pascal == case"
-        let pascal_count = judged_cases
+        // Only the camel case one, the remains are one or more words.
+        let hungarian_count = judged_cases
             .iter()
-            .filter(|case| matches!(case, NamingCase::Pascal(_)))
+            .filter(|case| {
+                case.as_ref().is_ok_and(|c| {
+                    c.kind() == CaseKind::SingleWord
+                        || c.kind() == CaseKind::Pascal
+                })
+            })
             .count();
 
-        let invalid_count = judged_cases
-            .iter()
-            .filter(|case| matches!(case, NamingCase::Invalid(_)))
-            .count();
+        let invalid_count =
+            judged_cases.iter().filter(|case| case.is_err()).count();
+
+        TestResult::from_bool(hungarian_count == 1 && invalid_count == 4)
+    }
+}
+
+#[test]
+fn hungarian_notation_kind_matches_its_string() {
+    let cases = [
+        ("iPageSize", CaseKind::Pascal, "PageSize"),
+        ("strUrl", CaseKind::SingleWord, "Url"),
+        ("aBC", CaseKind::SingleWord, "BC"),
+    ];
 
-        TestResult::from_bool(pascal_count == 1 && invalid_count == 4)
+    for (identifier, kind, remains) in cases {
+        let case = lib::from_hungarian_notation(identifier).unwrap();
+        assert_eq!(kind, case.kind(), "{}", identifier);
+        assert_eq!(remains, case.as_str());
+        assert_eq!(lib::which_case(remains).kind(), case.kind());
     }
 }
 
@@ -99,3 +117,157 @@ fn convert_test_helper(
 
     TestResult::from_bool(all_strs_can_be_correctly_converted)
 }
+
+#[quickcheck]
+fn keep_origin_string_and_report_matching_kind(s: String) -> bool {
+    let case = lib::which_case(&s);
+    let kind_matches = match case.kind() {
+        CaseKind::SingleWord => lib::is_single_word(&s),
+        CaseKind::ScreamingSnake => lib::is_screaming_snake(&s),
+        CaseKind::Snake => lib::is_snake(&s),
+        CaseKind::Kebab => lib::is_kebab(&s),
+        CaseKind::Camel => lib::is_camel(&s),
+        CaseKind::Pascal => lib::is_pascal(&s),
+        CaseKind::Invalid => case.to_snake().is_err(),
+    };
+    case.as_str() == s && kind_matches
+}
+
+#[test]
+fn borrow_the_identifier_until_into_owned() {
+    let identifier = String::from("fooBar");
+    let case = lib::which_case(&identifier);
+    assert_eq!(identifier.as_ptr(), case.as_str().as_ptr());
+
+    let owned: NamingCase<'static> = case.into_owned();
+    drop(identifier);
+    assert_eq!("fooBar", owned.as_str());
+    assert_eq!(CaseKind::Camel, owned.kind());
+}
+
+#[quickcheck]
+fn parse_same_as_which_case(s: String) -> bool {
+    let parsed: NamingCase = s.parse().unwrap();
+    let detected = lib::which_case(&s);
+    parsed == detected && parsed.kind() == detected.kind()
+}
+
+#[test]
+fn compare_hash_and_sort_by_included_string() {
+    use std::collections::{BTreeSet, HashSet};
+
+    let names = ["fooBar", "foo_bar", "FOO", "foo bar"];
+    let hashed: HashSet<NamingCase> =
+        names.iter().map(|s| s.parse().unwrap()).collect();
+    assert!(names.iter().all(|name| hashed.contains(*name)));
+
+    let mut sorted: Vec<NamingCase> =
+        names.iter().map(|s| lib::which_case(s)).collect();
+    sorted.sort();
+    let mut expected = names;
+    expected.sort_unstable();
+    assert_eq!(
+        expected.to_vec(),
+        sorted.iter().map(|c| c.as_str()).collect::<Vec<_>>()
+    );
+
+    let ordered: BTreeSet<NamingCase> = sorted.iter().cloned().collect();
+    assert_eq!(names.len(), ordered.len());
+}
+
+#[quickcheck]
+fn equal_instances_have_equal_kinds(s: String) -> bool {
+    let detected = lib::which_case(&s);
+    let parsed: NamingCase = s.parse().unwrap();
+    let same_as_detected = |case: &NamingCase| {
+        lib::which_case(case.as_str()).kind() == case.kind()
+    };
+
+    detected == parsed
+        && detected.kind() == parsed.kind()
+        && lib::from_hungarian_notation(&s)
+            .map_or(true, |h| same_as_detected(&h))
+}
+
+#[test]
+fn instances_of_one_string_have_one_kind() {
+    use std::collections::HashSet;
+
+    let pascal = lib::which_case("NotACamelCase");
+    let from_hungarian =
+        lib::from_hungarian_notation("aNotACamelCase").unwrap();
+    assert_eq!(pascal, from_hungarian);
+    assert_eq!(pascal.kind(), from_hungarian.kind());
+
+    let names: HashSet<NamingCase> =
+        vec![pascal, from_hungarian].into_iter().collect();
+    assert_eq!(1, names.len());
+}
+
+#[test]
+fn take_the_included_string() {
+    let case = lib::which_case("fooBar");
+    assert_eq!("fooBar", AsRef::<str>::as_ref(&case));
+    assert_eq!("fooBar", case.clone().into_inner());
+    assert_eq!("fooBar", String::from(case));
+}
+
+#[quickcheck]
+fn conversions_keep_the_same_words(word: String) -> TestResult {
+    // Repeated single letters like "AAA" are recognized as single words.
+    if is_not_valid_single_word(&word) || word.len() < 2 {
+        return TestResult::discard();
+    }
+
+    let keep_words = |s: &String| {
+        let case = lib::from(s);
+        [
+            case.to_screaming_snake(),
+            case.to_snake(),
+            case.to_kebab(),
+            case.to_camel(),
+            case.to_pascal(),
+        ]
+        .iter()
+        .all(|converted| {
+            case.same_words(&lib::from(converted.as_ref().unwrap()))
+        })
+    };
+    TestResult::from_bool(build_all_format_str(word).iter().all(keep_words))
+}
+
+#[test]
+fn compare_words_of_identifiers() {
+    assert!(lib::from("userId").same_words(&lib::from("USER_ID")));
+    assert!(lib::from("UserId").same_words(&lib::from("user-id")));
+    assert!(!lib::from("userId").same_words(&lib::from("userIds")));
+
+    // Invalid identifiers only have the same words as identical strings.
+    assert!(lib::from("user id").same_words(&lib::from("user id")));
+    assert!(!lib::from("user id").same_words(&lib::from("userId")));
+    assert!(lib::from("user id").normalization_key().is_err());
+}
+
+#[test]
+fn extract_words_as_written() {
+    assert_eq!(
+        vec!["foo", "Bar", "Baz"],
+        lib::from("fooBarBaz").words().unwrap()
+    );
+    assert_eq!(vec!["foo", "bar"], lib::from("foo-bar").words().unwrap());
+    assert_eq!(vec!["Foo1"], lib::from("Foo1").words().unwrap());
+    assert!(lib::from("foo bar").words().is_err());
+}
+
+#[test]
+fn parse_names_of_target_cases() {
+    for target in TargetCase::ALL {
+        assert_eq!(Ok(target), target.name().parse());
+        assert_eq!(target.name(), target.to_string());
+    }
+    assert_eq!(Ok(TargetCase::ScreamingSnake), "screaming_snake".parse());
+    assert!("Snake".parse::<TargetCase>().is_err());
+
+    assert_eq!("single-word", CaseKind::SingleWord.to_string());
+    assert_eq!("invalid", CaseKind::Invalid.name());
+}
@@ -0,0 +1,150 @@
+#![cfg(any(feature = "serde_json", feature = "toml"))]
+
+use naming_lib::rekey::RekeyOptions;
+use naming_lib::TargetCase;
+
+#[cfg(feature = "serde_json")]
+mod json_value {
+    use serde_json::json;
+
+    use naming_lib::rekey::{rekey_json, InvalidKeys, OnCollision};
+
+    use super::*;
+
+    #[test]
+    fn convert_keys_of_nested_objects_and_arrays() {
+        let mut value = json!({
+            "userId": 1,
+            "recentOrders": [{"orderId": 2, "lineItems": [{"skuCode": "keepValue"}]}],
+        });
+
+        rekey_json(&mut value, &RekeyOptions::new(TargetCase::Snake)).unwrap();
+
+        assert_eq!(
+            json!({
+                "user_id": 1,
+                "recent_orders": [{"order_id": 2, "line_items": [{"sku_code": "keepValue"}]}],
+            }),
+            value
+        );
+    }
+
+    #[test]
+    fn stop_at_max_depth() {
+        let mut value = json!({"outerKey": {"innerKey": {"deepKey": 1}}});
+        let mut options = RekeyOptions::new(TargetCase::Kebab);
+        options.max_depth = Some(2);
+
+        rekey_json(&mut value, &options).unwrap();
+
+        assert_eq!(json!({"outer-key": {"inner-key": {"deepKey": 1}}}), value);
+    }
+
+    #[test]
+    fn keep_excluded_keys_and_their_values() {
+        let mut value =
+            json!({"rawHeaders": {"contentType": "a"}, "userId": 1});
+        let mut options = RekeyOptions::new(TargetCase::Snake);
+        options.exclude.push("rawHeaders".to_string());
+
+        rekey_json(&mut value, &options).unwrap();
+
+        assert_eq!(
+            json!({"rawHeaders": {"contentType": "a"}, "user_id": 1}),
+            value
+        );
+    }
+
+    #[test]
+    fn keep_or_lossily_convert_invalid_keys() {
+        let original = json!({"user id": 1, "@@": 2});
+
+        let mut value = original.clone();
+        rekey_json(&mut value, &RekeyOptions::new(TargetCase::Camel)).unwrap();
+        assert_eq!(original, value);
+
+        let mut options = RekeyOptions::new(TargetCase::Camel);
+        options.invalid_keys = InvalidKeys::Lossy;
+        rekey_json(&mut value, &options).unwrap();
+        assert_eq!(json!({"userId": 1, "@@": 2}), value);
+    }
+
+    #[test]
+    fn walk_into_values_of_invalid_keys() {
+        let original =
+            json!({"@meta": {"user_id": 1}, "@@": {"home_address": 2}});
+
+        let mut value = original.clone();
+        rekey_json(&mut value, &RekeyOptions::new(TargetCase::Camel)).unwrap();
+        assert_eq!(
+            json!({"@meta": {"userId": 1}, "@@": {"homeAddress": 2}}),
+            value
+        );
+
+        let mut options = RekeyOptions::new(TargetCase::Camel);
+        options.invalid_keys = InvalidKeys::Lossy;
+        let mut value = original;
+        rekey_json(&mut value, &options).unwrap();
+        assert_eq!(
+            json!({"meta": {"userId": 1}, "@@": {"homeAddress": 2}}),
+            value
+        );
+    }
+
+    #[test]
+    fn resolve_collisions_by_chosen_rule() {
+        let original = json!({"data": {"fooBar": 1, "foo_bar": 2}});
+        let mut options = RekeyOptions::new(TargetCase::Snake);
+
+        let mut value = original.clone();
+        rekey_json(&mut value, &options).unwrap();
+        assert_eq!(json!({"data": {"foo_bar": 2}}), value);
+
+        options.on_collision = OnCollision::KeepFirst;
+        let mut value = original.clone();
+        rekey_json(&mut value, &options).unwrap();
+        assert_eq!(json!({"data": {"foo_bar": 1}}), value);
+
+        options.on_collision = OnCollision::Error;
+        let mut value = original.clone();
+        let collision = rekey_json(&mut value, &options).unwrap_err();
+        assert_eq!(vec!["data".to_string()], collision.path);
+        assert_eq!(
+            ("fooBar".to_string(), "foo_bar".to_string()),
+            collision.keys
+        );
+        assert_eq!("foo_bar", collision.converted);
+        assert_eq!(original, value);
+    }
+}
+
+#[cfg(feature = "toml")]
+mod toml_value {
+    use naming_lib::rekey::rekey_toml;
+
+    use super::*;
+
+    #[test]
+    fn convert_keys_of_tables_and_arrays_of_tables() {
+        let mut value: toml::Value = toml::from_str(
+            r#"
+            serverName = "a"
+            [[listenPorts]]
+            portNumber = 80
+            "#,
+        )
+        .unwrap();
+
+        rekey_toml(&mut value, &RekeyOptions::new(TargetCase::Snake)).unwrap();
+
+        let expected: toml::Value = toml::from_str(
+            r#"
+            server_name = "a"
+            [[listen_ports]]
+            port_number = 80
+            "#,
+        )
+        .unwrap();
+        assert_eq!(expected, value);
+    }
+}
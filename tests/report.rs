@@ -0,0 +1,160 @@
+use std::path::Path;
+
+use naming_lib::language::Language;
+use naming_lib::lint::{report, Diagnostic, Format, Linter};
+
+fn diagnostics() -> Vec<Diagnostic> {
+    let linter = Linter::default();
+    let mut diagnostics = linter.lint_source(
+        Path::new("src/main.rs"),
+        Language::Rust,
+        "fn parseArgs() {}\nstruct http_server;\n",
+    );
+    diagnostics.append(&mut linter.lint_source(
+        Path::new("my app/Main.java"),
+        Language::Java,
+        "class Main { int Item_Count; }",
+    ));
+    diagnostics
+}
+
+fn reported(diagnostics: &[Diagnostic], format: Format) -> String {
+    let mut out = Vec::new();
+    report(diagnostics, format, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn parse_format_names() {
+    for format in Format::ALL.iter() {
+        assert_eq!(Ok(*format), format.name().parse());
+        assert_eq!(format.name(), format.to_string());
+    }
+    assert_eq!(Err("Unknown report format name."), "xml".parse::<Format>());
+}
+
+#[test]
+fn report_text_and_json_lines() {
+    let diagnostics = diagnostics();
+    assert_eq!(3, diagnostics.len());
+
+    let text = reported(&diagnostics, Format::Text);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(
+        "src/main.rs:2:8: type `http_server` should be pascal case, \
+         found snake (try `HttpServer`)",
+        lines[1]
+    );
+
+    let json = reported(&diagnostics, Format::Json);
+    let objects: Vec<serde_json::Value> =
+        json.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    assert_eq!(3, objects.len());
+    assert_eq!(
+        serde_json::json!({
+            "path": "src/main.rs",
+            "line": 1,
+            "column": 4,
+            "language": "rust",
+            "identifier": "parseArgs",
+            "kind": "function",
+            "case": "camel",
+            "expected": "snake",
+            "suggestion": "parse_args",
+        }),
+        objects[0]
+    );
+
+    assert!(reported(&[], Format::Json).is_empty());
+}
+
+#[test]
+fn report_sarif() {
+    let sarif: serde_json::Value =
+        serde_json::from_str(&reported(&diagnostics(), Format::Sarif)).unwrap();
+    assert_eq!("2.1.0", sarif["version"]);
+
+    let run = &sarif["runs"][0];
+    assert_eq!("naming", run["tool"]["driver"]["name"]);
+    let rules: Vec<&str> = run["tool"]["driver"]["rules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|rule| rule["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(vec!["naming/type", "naming/function", "naming/field"], rules);
+
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(3, results.len());
+    let result = &results[1];
+    assert_eq!("naming/type", result["ruleId"]);
+    assert_eq!(0, result["ruleIndex"]);
+    assert_eq!("warning", result["level"]);
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!("src/main.rs", location["artifactLocation"]["uri"]);
+    assert_eq!(
+        serde_json::json!({"startLine": 2, "startColumn": 8, "endColumn": 19}),
+        location["region"]
+    );
+    assert_eq!("snake", result["properties"]["detected"]);
+    assert_eq!("pascal", result["properties"]["expected"]);
+    let replacement =
+        &result["fixes"][0]["artifactChanges"][0]["replacements"][0];
+    assert_eq!("HttpServer", replacement["insertedContent"]["text"]);
+
+    let location = &results[2]["locations"][0]["physicalLocation"];
+    assert_eq!("my%20app/Main.java", location["artifactLocation"]["uri"]);
+    assert_eq!("itemCount", results[2]["properties"]["suggestion"]);
+
+    let empty: serde_json::Value =
+        serde_json::from_str(&reported(&[], Format::Sarif)).unwrap();
+    assert_eq!(serde_json::json!([]), empty["runs"][0]["results"]);
+}
+
+#[test]
+fn report_checkstyle() {
+    let mut diagnostics = diagnostics();
+    diagnostics[0].identifier = "parse<\"Args\">".to_string();
+
+    assert_eq!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <checkstyle version=\"4.3\">\n  \
+         <file name=\"src/main.rs\">\n    \
+         <error line=\"1\" column=\"4\" severity=\"warning\" \
+         message=\"function `parse&lt;&quot;Args&quot;&gt;` should be snake case, \
+         found camel (try `parse_args`)\" source=\"naming/function\"/>\n    \
+         <error line=\"2\" column=\"8\" severity=\"warning\" \
+         message=\"type `http_server` should be pascal case, \
+         found snake (try `HttpServer`)\" source=\"naming/type\"/>\n  \
+         </file>\n  \
+         <file name=\"my app/Main.java\">\n    \
+         <error line=\"1\" column=\"18\" severity=\"warning\" \
+         message=\"field `Item_Count` should be camel case, \
+         found invalid (try `itemCount`)\" source=\"naming/field\"/>\n  \
+         </file>\n\
+         </checkstyle>\n",
+        reported(&diagnostics, Format::Checkstyle)
+    );
+}
+
+#[test]
+fn report_github_annotations() {
+    let mut diagnostics = diagnostics();
+    diagnostics[0].location.path = "a,b:c%.rs".into();
+    diagnostics[0].identifier = "x\ny".to_string();
+
+    let annotations = reported(&diagnostics, Format::Github);
+    let lines: Vec<&str> = annotations.lines().collect();
+    assert_eq!(
+        "::warning file=a%2Cb%3Ac%25.rs,line=1,col=4,endColumn=7,\
+         title=naming/function::function `x%0Ay` should be snake case, \
+         found camel (try `parse_args`)",
+        lines[0]
+    );
+    assert_eq!(
+        "::warning file=src/main.rs,line=2,col=8,endColumn=19,\
+         title=naming/type::type `http_server` should be pascal case, \
+         found snake (try `HttpServer`)",
+        lines[1]
+    );
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "serde")]
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use naming_lib::serde::{Deserializer, Serializer};
+use naming_lib::TargetCase;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Account {
+    user_id: u32,
+    display_name: String,
+    home_address: Option<Address>,
+    extra_fields: BTreeMap<String, String>,
+    last_event: Event,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Address {
+    street_name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+enum Event {
+    SignedIn { client_version: u32 },
+}
+
+fn account() -> Account {
+    let mut extra_fields = BTreeMap::new();
+    extra_fields.insert("favoriteColor".to_string(), "dark_blue".to_string());
+    extra_fields.insert("@invalid key".to_string(), "kept".to_string());
+
+    Account {
+        user_id: 7,
+        display_name: "first_name".to_string(),
+        home_address: Some(Address { street_name: "main_street".to_string() }),
+        extra_fields,
+        last_event: Event::SignedIn { client_version: 2 },
+    }
+}
+
+fn to_json(account: &Account, target: TargetCase) -> String {
+    let mut out = Vec::new();
+    let mut json = serde_json::Serializer::new(&mut out);
+    account.serialize(Serializer::new(&mut json, target)).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+fn from_json(input: &str, target: TargetCase) -> Account {
+    let mut json = serde_json::Deserializer::from_str(input);
+    Account::deserialize(Deserializer::new(&mut json, target)).unwrap()
+}
+
+#[test]
+fn serializer_renames_field_names_and_map_keys_but_not_values() {
+    assert_eq!(
+        concat!(
+            r#"{"userId":7,"displayName":"first_name","#,
+            r#""homeAddress":{"streetName":"main_street"},"#,
+            r#""extraFields":{"@invalid key":"kept","favoriteColor":"dark_blue"},"#,
+            r#""lastEvent":{"SignedIn":{"clientVersion":2}}}"#
+        ),
+        to_json(&account(), TargetCase::Camel)
+    );
+}
+
+#[test]
+fn serializer_renames_into_runtime_chosen_case() {
+    let json = to_json(&account(), TargetCase::ScreamingSnake);
+    assert!(json.starts_with(r#"{"USER_ID":7,"DISPLAY_NAME":"first_name""#));
+    assert!(json.contains(r#""FAVORITE_COLOR":"dark_blue""#));
+}
+
+#[test]
+fn deserializer_renames_keys_into_field_names() {
+    let input = concat!(
+        r#"{"userId":7,"displayName":"first_name","#,
+        r#""homeAddress":{"streetName":"main_street"},"#,
+        r#""extraFields":{"@invalid key":"kept","favorite-color":"dark_blue"},"#,
+        r#""lastEvent":{"SignedIn":{"ClientVersion":2}}}"#
+    );
+
+    let mut expected = account();
+    expected.extra_fields.remove("favoriteColor");
+    expected
+        .extra_fields
+        .insert("favorite_color".to_string(), "dark_blue".to_string());
+
+    assert_eq!(expected, from_json(input, TargetCase::Snake));
+}
+
+#[test]
+fn output_of_one_case_can_be_read_back_from_another() {
+    let camel = to_json(&account(), TargetCase::Camel);
+    let mut read = from_json(&camel, TargetCase::Snake);
+    read.extra_fields = account().extra_fields;
+
+    assert_eq!(account(), read);
+}
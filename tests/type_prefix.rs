@@ -0,0 +1,109 @@
+use naming_lib::type_prefix::{
+    parse_type_prefix, PrefixPolicy, TypePrefix, TypePrefixRecognizer,
+};
+use naming_lib::{CaseKind, TargetCase};
+
+#[test]
+fn recognize_type_prefixes() {
+    let cases = [
+        ("IDisposable", TypePrefix::Interface, "Disposable"),
+        ("IHttpClient", TypePrefix::Interface, "HttpClient"),
+        ("TKey", TypePrefix::TypeParameter, "Key"),
+        ("TForm1", TypePrefix::TypeParameter, "Form1"),
+        ("EColor", TypePrefix::Enum, "Color"),
+        ("IDisposableIDs", TypePrefix::Interface, "DisposableIDs"),
+    ];
+
+    for (identifier, prefix, rest) in cases {
+        let parsed = parse_type_prefix(identifier);
+        assert_eq!(Some(prefix), parsed.prefix, "{}", identifier);
+        assert_eq!(rest, parsed.name.as_str());
+    }
+    assert_eq!(CaseKind::Pascal, parse_type_prefix("IHttpClient").name.kind());
+}
+
+#[test]
+fn not_type_prefixes() {
+    for identifier in [
+        "IOError",
+        "IDCard",
+        "TCPServer",
+        "Item",
+        "Type",
+        "Enum",
+        "I",
+        "IO",
+        "ID",
+        "IDs",
+        "IPs",
+        "IPv4",
+        "IPv6Address",
+        "ETag",
+        "ETagCache",
+        "iFoo",
+        "i_foo",
+        "XFoo",
+    ] {
+        let parsed = parse_type_prefix(identifier);
+        assert_eq!(None, parsed.prefix, "{}", identifier);
+        assert_eq!(identifier, parsed.name.as_str());
+    }
+}
+
+#[test]
+fn convert_with_policies() {
+    let parsed = parse_type_prefix("IHttpClient");
+    let cases = [
+        (TargetCase::Snake, PrefixPolicy::Keep, "i_http_client"),
+        (TargetCase::Snake, PrefixPolicy::Strip, "http_client"),
+        (TargetCase::Snake, PrefixPolicy::Normalize, "i_http_client"),
+        (TargetCase::Camel, PrefixPolicy::Keep, "iHttpClient"),
+        (TargetCase::Camel, PrefixPolicy::Strip, "httpClient"),
+        (TargetCase::Camel, PrefixPolicy::Normalize, "IHttpClient"),
+        (TargetCase::Pascal, PrefixPolicy::Strip, "HttpClient"),
+        (TargetCase::Pascal, PrefixPolicy::Normalize, "IHttpClient"),
+        (TargetCase::Kebab, PrefixPolicy::Strip, "http-client"),
+    ];
+
+    for (target, policy, expected) in cases {
+        assert_eq!(
+            expected,
+            parsed.to_case(target, policy).unwrap(),
+            "{:?} {:?}",
+            target,
+            policy
+        );
+    }
+
+    // Nothing to handle without a prefix.
+    let parsed = parse_type_prefix("IOError");
+    for policy in [PrefixPolicy::Keep, PrefixPolicy::Strip] {
+        assert_eq!(
+            "i_o_error",
+            parsed.to_case(TargetCase::Snake, policy).unwrap()
+        );
+    }
+}
+
+#[test]
+fn configure_prefixes_and_exceptions() {
+    let mut interfaces = TypePrefixRecognizer::new(&[TypePrefix::Interface]);
+    assert!(interfaces.exceptions().is_empty());
+    assert_eq!(None, interfaces.parse("TKey").prefix);
+    assert_eq!(Some(TypePrefix::Interface), interfaces.parse("IDs").prefix);
+
+    interfaces.add_exception("IDs");
+    interfaces.add_exception("IDs");
+    assert_eq!(1, interfaces.exceptions().len());
+    assert_eq!(None, interfaces.parse("IDs").prefix);
+    assert_eq!(None, interfaces.parse("IDsToFetch").prefix);
+    assert_eq!(Some(TypePrefix::Interface), interfaces.parse("IDsl").prefix);
+
+    assert!(interfaces.remove_exception("IDs"));
+    assert!(!interfaces.remove_exception("IDs"));
+    assert_eq!(Some(TypePrefix::Interface), interfaces.parse("IDs").prefix);
+
+    let mut default = TypePrefixRecognizer::default();
+    assert!(default.remove_exception("ETag"));
+    assert_eq!(Some(TypePrefix::Enum), default.parse("ETag").prefix);
+}
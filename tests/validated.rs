@@ -0,0 +1,86 @@
+#[cfg(test)]
+extern crate quickcheck;
+#[cfg(test)]
+#[macro_use(quickcheck)]
+extern crate quickcheck_macros;
+
+use std::convert::TryFrom;
+
+use quickcheck::TestResult;
+
+use common::*;
+use naming_lib as lib;
+use naming_lib::{
+    CamelCase, KebabCase, PascalCase, ScreamingSnakeCase, SnakeCase,
+};
+
+mod common;
+
+#[quickcheck]
+fn accept_identifiers_passing_detectors(s: String) -> bool {
+    ScreamingSnakeCase::try_from(s.as_str()).is_ok()
+        == lib::is_screaming_snake(&s)
+        && SnakeCase::try_from(s.as_str()).is_ok() == lib::is_snake(&s)
+        && KebabCase::try_from(s.as_str()).is_ok() == lib::is_kebab(&s)
+        && CamelCase::try_from(s.as_str()).is_ok() == lib::is_camel(&s)
+        && PascalCase::try_from(s.as_str()).is_ok() == lib::is_pascal(&s)
+}
+
+#[quickcheck]
+fn conversions_give_valid_identifiers(word: String) -> TestResult {
+    if is_not_valid_single_word(&word) {
+        return TestResult::discard();
+    }
+
+    let identifiers = build_all_format_str(word);
+    let camel = CamelCase::new(&identifiers[3]).unwrap();
+    let pascal = PascalCase::from(camel.clone());
+    let snake = SnakeCase::from(pascal.clone());
+    let kebab = KebabCase::from(snake.clone());
+    let screaming = ScreamingSnakeCase::from(kebab.clone());
+
+    TestResult::from_bool(
+        lib::is_pascal(&pascal)
+            && lib::is_snake(&snake)
+            && lib::is_kebab(&kebab)
+            && lib::is_screaming_snake(&screaming)
+            && lib::is_camel(&CamelCase::from(screaming)),
+    )
+}
+
+#[test]
+fn deref_to_the_included_identifier() {
+    let snake = SnakeCase::try_from("foo_bar").unwrap();
+    assert_eq!("foo_bar", &*snake);
+    assert_eq!(7, snake.len());
+    assert_eq!("foo_bar", snake.to_string());
+    assert_eq!("foo_bar", String::from(snake));
+}
+
+#[test]
+fn reject_identifiers_in_other_formats() {
+    assert!(SnakeCase::try_from("foo-bar").is_err());
+    assert!(KebabCase::try_from("foo_bar").is_err());
+    assert!(CamelCase::try_from("FooBar").is_err());
+    assert!(PascalCase::try_from("fooBar").is_err());
+    assert!(ScreamingSnakeCase::try_from("Foo_Bar").is_err());
+}
+
+#[test]
+fn convert_between_formats() {
+    let pascal = PascalCase::try_from("HttpServer").unwrap();
+    assert_eq!("http_server", SnakeCase::from(pascal.clone()).as_str());
+    assert_eq!("http-server", KebabCase::from(pascal.clone()).as_str());
+    assert_eq!("httpServer", CamelCase::from(pascal.clone()).as_str());
+    assert_eq!("HTTP_SERVER", ScreamingSnakeCase::from(pascal).as_str());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn reject_invalid_identifiers_during_deserialization() {
+    let snake: SnakeCase = serde_json::from_str(r#""foo_bar""#).unwrap();
+    assert_eq!(r#""foo_bar""#, serde_json::to_string(&snake).unwrap());
+
+    let error = serde_json::from_str::<SnakeCase>(r#""fooBar""#).unwrap_err();
+    assert!(error.to_string().contains("isn't in snake case"));
+}